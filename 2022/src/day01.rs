@@ -1,4 +1,4 @@
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use log::debug;
 
 #[derive(Debug, Clone)]
@@ -21,16 +21,16 @@ impl Elf {
     }
 }
 
-pub fn part1() -> Result<String, AocError> {
-    let elves = get_elves_sorted_descending()?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let elves = get_elves_sorted_descending(&input)?;
     let result = elves.first().unwrap().total_calories;
     debug!("Top elf calories = {}", result);
 
     Ok(format!("{}", result))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let elves = get_elves_sorted_descending()?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let elves = get_elves_sorted_descending(&input)?;
 
     let top_three = &elves[0..3];
     for elf in top_three {
@@ -43,8 +43,8 @@ pub fn part2() -> Result<String, AocError> {
     Ok(format!("{}", result))
 }
 
-fn get_elves_sorted_descending() -> Result<Vec<Elf>, std::io::Error> {
-    let lines = InputType::Challenge.get_input_lines(1)?;
+fn get_elves_sorted_descending(input: &DailyInput) -> Result<Vec<Elf>, std::io::Error> {
+    let lines = input.get_input_lines()?;
     let mut elves = Vec::<Elf>::new();
     let mut create_new_elf = true;
     for line in lines {
@@ -6,7 +6,7 @@ use std::{
     str::FromStr,
 };
 
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 
 #[derive(Debug, Eq)]
 pub enum Play {
@@ -105,8 +105,8 @@ struct Guide {
 }
 
 impl Guide {
-    fn parse_guide() -> Result<Guide, std::io::Error> {
-        let lines = InputType::Challenge.get_input_lines(2)?;
+    fn parse_guide(input: &DailyInput) -> Result<Guide, std::io::Error> {
+        let lines = input.get_input_lines()?;
 
         let mut turns = Vec::<(EncodedPlay, EncodedPlay)>::new();
         let mut line_number = 0;
@@ -224,8 +224,8 @@ fn parse_guide_line(line: &str) -> Result<(EncodedPlay, EncodedPlay), Error> {
     Ok((opponents_play, response))
 }
 
-pub fn part1() -> Result<String, AocError> {
-    let guide = Guide::parse_guide()?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let guide = Guide::parse_guide(&input)?;
     let result = guide
         .scores_for_scheme(&HashMap::from([
             (EncodedPlay::A, Play::ROCK),
@@ -239,8 +239,8 @@ pub fn part1() -> Result<String, AocError> {
     Ok(format!("{}", result))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let guide = Guide::parse_guide()?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let guide = Guide::parse_guide(&input)?;
     let result = guide
         .scores_for_scheme2(&HashMap::from([
             (EncodedPlay::A, Play::ROCK),
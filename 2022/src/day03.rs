@@ -1,4 +1,4 @@
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use log::debug;
 use std::collections::HashSet;
 
@@ -34,8 +34,8 @@ fn to_u8_set(str: &str) -> HashSet<char> {
     set
 }
 
-pub fn part1() -> Result<String, AocError> {
-    let lines = InputType::Challenge.get_input_lines(3)?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let lines = input.get_input_lines()?;
 
     let mut priority_sum = 0;
 
@@ -75,8 +75,8 @@ pub fn part1() -> Result<String, AocError> {
     Ok(format!("{}", priority_sum))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let lines = InputType::Challenge.get_input_lines(3)?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let lines = input.get_input_lines()?;
 
     let group_count_f = ((lines.len() as u32) as f32) / 3_f32;
     assert_eq!(group_count_f.floor(), group_count_f);
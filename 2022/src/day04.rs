@@ -1,4 +1,7 @@
-use crate::common::{AocError, InputType};
+use crate::{
+    common::{AocError, DailyInput},
+    intervals::IntervalSet,
+};
 use log::debug;
 use std::{
     io::{Error, ErrorKind},
@@ -26,22 +29,19 @@ fn to_range(s: &str) -> Result<RangeInclusive<u32>, Error> {
 }
 
 pub fn is_full_overlap(r0: &RangeInclusive<u32>, r1: &RangeInclusive<u32>) -> bool {
-    (r0.contains(r1.start()) && r0.contains(r1.end()))
-        || (r1.contains(r0.start()) && r1.contains(r0.end()))
+    let (s0, s1) = (IntervalSet::from(r0.clone()), IntervalSet::from(r1.clone()));
+    s0.is_subset(&s1) || s1.is_subset(&s0)
 }
 
 pub fn is_any_overlap(r0: &RangeInclusive<u32>, r1: &RangeInclusive<u32>) -> bool {
-    r0.contains(r1.start())
-        || r0.contains(r1.end())
-        || r1.contains(r0.start())
-        || r1.contains(r0.end())
+    IntervalSet::from(r0.clone()).overlaps(&IntervalSet::from(r1.clone()))
 }
 
-fn get_results() -> Result<(i32, i32), AocError> {
+fn get_results(input: &DailyInput) -> Result<(i32, i32), AocError> {
     let mut line_number = 0;
     let mut count_full_overlap = 0;
     let mut count_any_overlap = 0;
-    for line in InputType::Challenge.get_input_lines(4)?.iter() {
+    for line in input.get_input_lines()?.iter() {
         line_number += 1;
 
         let parts = line.split(",").collect::<Vec<&str>>();
@@ -67,12 +67,12 @@ fn get_results() -> Result<(i32, i32), AocError> {
     Ok((count_full_overlap, count_any_overlap))
 }
 
-pub fn part1() -> Result<String, AocError> {
-    Ok(format!("{}", get_results()?.0))
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    Ok(format!("{}", get_results(&input)?.0))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    Ok(format!("{}", get_results()?.1))
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    Ok(format!("{}", get_results(&input)?.1))
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use crate::common::{AocError, InputType};
+use crate::{
+    common::{AocError, DailyInput},
+    grid::Grid,
+};
 use log::debug;
 use regex::Regex;
 use std::{
@@ -75,98 +78,85 @@ impl Stacks {
     }
 }
 
-pub fn part1() -> Result<String, AocError> {
-    let lines = InputType::Challenge.get_input_lines(5)?;
-    let mut stacks = parse_stacks(&lines);
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let (mut stacks, moves) = parse(&input)?;
 
     debug!("With the cm9000 crane algorithm");
     stacks.display();
-    let moves = parse_moves(&lines);
     for m in &moves {
         debug!("Applying move {:?}", m);
-        stacks.apply_move_with_cm9000(&m);
+        stacks.apply_move_with_cm9000(m);
         //        stacks.display();
     }
     Ok(stacks.get_top_boxes())
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let lines = InputType::Challenge.get_input_lines(5)?;
-    let mut stacks = parse_stacks(&lines);
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let (mut stacks, moves) = parse(&input)?;
     stacks.display();
-    let moves = parse_moves(&lines);
     for m in &moves {
         debug!("Applying move {:?}", m);
-        stacks.apply_move_with_cm9001(&m);
+        stacks.apply_move_with_cm9001(m);
         //        stacks.display();
     }
     Ok(stacks.get_top_boxes())
 }
 
-fn parse_moves(lines: &Vec<String>) -> Vec<Move> {
-    let move_re = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
-
-    let mut moves = Vec::<Move>::with_capacity(lines.len());
-    for line in lines.iter() {
-        match move_re.captures(line) {
-            Some(c) => {
-                moves.push(Move {
-                    quantity: str_to_usize(c.get(1).unwrap().as_str()).unwrap(),
-                    from_stack: str_to_usize(c.get(2).unwrap().as_str()).unwrap(),
-                    to_stack: str_to_usize(c.get(3).unwrap().as_str()).unwrap(),
-                });
-            }
-            None => {}
-        };
-    }
-    moves
+/// The drawing of the stacks, then a blank line, then the moves.
+fn parse(input: &DailyInput) -> Result<(Stacks, Vec<Move>), AocError> {
+    let sections = input.sections()?;
+    let [drawing, moves] = sections.as_slice() else {
+        return Err(AocError::ParseFailed {
+            message: format!(
+                "Expected stacks and moves but found {} sections",
+                sections.len()
+            ),
+        });
+    };
+    // editors tend to trim the trailing spaces off the top of shorter stacks
+    let drawing = Grid::parse_filled(drawing, b' ')?;
+    Ok((parse_stacks(&drawing), parse_moves(moves)))
 }
 
-fn parse_stacks(lines: &Vec<String>) -> Stacks {
-    let mut stacks: Vec<VecDeque<char>> = Vec::with_capacity(20);
-
-    for line in lines.iter() {
-        if line.is_empty() {
-            break;
-        }
-
-        if stacks.len() == 0 {
-            // L: length of line
-            // n: # of stacks
-            // w: # chars width for for one stack
-            // s: # chars providing space between each stack
-            // L = w*n+(n-1)*s = 3n+(n-1)*1 = 3n+n-1 = 4n-1  ==> n = (L+1)/4
-            let num_stacks = (line.len() + 1) / 4;
-            //debug!("Num Stacks {}", num_stacks);
-
-            for _n in 0..num_stacks {
-                stacks.push(VecDeque::new());
-            }
-        }
+fn parse_moves(text: &str) -> Vec<Move> {
+    let move_re = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
 
-        if stacks.len() == 0 {
-            panic!("Shouldn't be here without an allocated set of stacks");
-        }
-        let mut chars = line.chars();
-        for stack in 0..stacks.len() {
-            let next_char = match stack {
-                0 => chars.nth(1),
-                _ => chars.nth(3),
-            };
-
-            match next_char {
-                Some(next_char) => {
-                    //                debug!(" {} {}", stack, next_char);
-                    if next_char.is_alphabetic() {
-                        stacks[stack].push_front(next_char)
-                    }
-                }
-                None => break,
-            }
+    let mut moves = Vec::<Move>::new();
+    for line in text.lines() {
+        if let Some(c) = move_re.captures(line) {
+            moves.push(Move {
+                quantity: str_to_usize(c.get(1).unwrap().as_str()).unwrap(),
+                from_stack: str_to_usize(c.get(2).unwrap().as_str()).unwrap(),
+                to_stack: str_to_usize(c.get(3).unwrap().as_str()).unwrap(),
+            });
         }
     }
+    moves
+}
 
-    Stacks(stacks)
+/// Each stack's boxes are in every 4th col, read bottom up from just above the row of stack numbers.
+fn parse_stacks(drawing: &Grid) -> Stacks {
+    let boxes = drawing.row_count() - 1;
+    Stacks(
+        drawing
+            .cols()
+            .skip(1)
+            .step_by(4)
+            .map(|col| {
+                let mut stack = drawing
+                    .get_col(col)
+                    .unwrap()
+                    .take(boxes)
+                    .collect::<Vec<_>>();
+                stack.reverse();
+                stack
+                    .into_iter()
+                    .filter(u8::is_ascii_alphabetic)
+                    .map(char::from)
+                    .collect()
+            })
+            .collect(),
+    )
 }
 
 #[cfg(test)]
@@ -1,14 +1,14 @@
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use std::collections::HashSet;
 
-pub fn part1() -> Result<String, AocError> {
-    let lines = InputType::Challenge.get_input_lines(6)?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let lines = input.get_input_lines()?;
     let line = lines.get(0).unwrap();
     Ok(find_marker_of_length(&line, 4))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let lines = InputType::Challenge.get_input_lines(6)?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let lines = input.get_input_lines()?;
     let line = lines.get(0).unwrap();
     Ok(find_marker_of_length(&line, 14))
 }
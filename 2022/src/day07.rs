@@ -1,4 +1,4 @@
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use core::fmt;
 use log::debug;
 use nom::{
@@ -159,8 +159,8 @@ fn new_dir_ref(dir: Directory) -> DirRef {
     Rc::new(RefCell::new(dir))
 }
 
-pub fn part1() -> Result<String, AocError> {
-    let root_directory = build_dir_tree()?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let root_directory = build_dir_tree(&input)?;
 
     debug!("PART1: Dir tree");
     root_directory.borrow().display_dir_tree(" - ");
@@ -185,8 +185,8 @@ pub fn part1() -> Result<String, AocError> {
     Ok(format!("{}", sum_smaller_dirs_total_sizes))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let root_directory = build_dir_tree()?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let root_directory = build_dir_tree(&input)?;
 
     let mut all_dirs = Vec::<DirRef>::new();
     add_if(&root_directory, &|_| true, &mut all_dirs);
@@ -218,8 +218,8 @@ pub fn part2() -> Result<String, AocError> {
     }
 }
 
-fn build_dir_tree() -> Result<Rc<RefCell<Directory>>, Error> {
-    let lines = InputType::Challenge.get_input_lines(7)?;
+fn build_dir_tree(input: &DailyInput) -> Result<Rc<RefCell<Directory>>, Error> {
+    let lines = input.get_input_lines()?;
     let root_directory = new_dir_ref(Directory::new("/"));
     let mut path: Vec<DirRef> = vec![root_directory.clone()];
     for line in lines {
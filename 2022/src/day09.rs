@@ -1,4 +1,4 @@
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use log::debug;
 use std::{
     collections::HashSet,
@@ -31,8 +31,8 @@ impl FromStr for Direction {
     }
 }
 
-fn parse_moves() -> Result<Vec<(Direction, u16)>, Error> {
-    let lines = InputType::Challenge.get_input_lines(9)?;
+fn parse_moves(input: &DailyInput) -> Result<Vec<(Direction, u16)>, Error> {
+    let lines = input.get_input_lines()?;
 
     Ok(lines
         .iter()
@@ -265,11 +265,11 @@ mod tests {
     }
 }
 
-pub fn part1() -> Result<String, AocError> {
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
     let mut sim = Simulation::new(2);
     debug!("{:?}", sim);
     let mut set = HashSet::<XYPair>::new();
-    for m in &parse_moves()? {
+    for m in &parse_moves(&input)? {
         for _ in 0..m.1 {
             sim.step(&m.0);
             // debug!("{:?} -> {}", m.0, sim);
@@ -279,11 +279,11 @@ pub fn part1() -> Result<String, AocError> {
     Ok(format!("Tail has been in {} unique locations.", set.len()))
 }
 
-pub fn part2() -> Result<String, AocError> {
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
     let mut sim = Simulation::new(10);
     //debug!("{:?}", sim);
     let mut set = HashSet::<XYPair>::new();
-    for m in &parse_moves()? {
+    for m in &parse_moves(&input)? {
         for _ in 0..m.1 {
             sim.step(&m.0);
             //debug!("{:?} -> {}", m.0, sim);
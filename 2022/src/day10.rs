@@ -1,4 +1,4 @@
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use std::io::Error;
 
 #[derive(Debug)]
@@ -16,8 +16,8 @@ impl Op {
     }
 }
 
-fn get_cycle_start_and_end() -> Result<Vec<(i32, i32)>, Error> {
-    let instructions = get_instructions()?;
+fn get_cycle_start_and_end(input: &DailyInput) -> Result<Vec<(i32, i32)>, Error> {
+    let instructions = get_instructions(input)?;
 
     let mut x = 1_i32;
     let xs: Vec<(i32, i32)> = instructions
@@ -36,8 +36,8 @@ fn get_cycle_start_and_end() -> Result<Vec<(i32, i32)>, Error> {
     Ok(xs)
 }
 
-fn get_signal_strengths() -> Result<Vec<i32>, Error> {
-    let se = get_cycle_start_and_end()?;
+fn get_signal_strengths(input: &DailyInput) -> Result<Vec<i32>, Error> {
+    let se = get_cycle_start_and_end(input)?;
 
     let mut values_as_product = vec![1_i32; se.len()];
     for i in 0_usize..se.len() {
@@ -47,21 +47,19 @@ fn get_signal_strengths() -> Result<Vec<i32>, Error> {
     Ok(values_as_product)
 }
 
-pub fn part1() -> Result<String, AocError> {
-    let values = get_signal_strengths()?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let values = get_signal_strengths(&input)?;
 
     let mut sum: i32 = 0;
     for i in (20..=220).step_by(40) {
         sum += values[i - 1];
     }
 
-    assert_eq!(sum, 13220);
-
     Ok(format!("{}", sum))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let xs = get_cycle_start_and_end()?
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let xs = get_cycle_start_and_end(&input)?
         .iter()
         .map(|p| p.0)
         .collect::<Vec<i32>>();
@@ -90,8 +88,8 @@ pub fn part2() -> Result<String, AocError> {
     Ok(result)
 }
 
-fn get_instructions() -> Result<Vec<Op>, Error> {
-    let lines = InputType::Challenge.get_input_lines(10)?;
+fn get_instructions(input: &DailyInput) -> Result<Vec<Op>, Error> {
+    let lines = input.get_input_lines()?;
     let mut cycle_ops: Vec<Op> = Vec::with_capacity(240);
     for line in &lines {
         let mut splitter = line.split(" ");
@@ -4,24 +4,23 @@ use self::{
     model::{DivisibleBy, Monkey, Operation, ThrowToMonkey},
     parse::load_input,
 };
-use crate::common::AocError;
+use crate::common::{AocError, DailyInput};
 use log::{debug, max_level, LevelFilter};
 use std::ops::{Add, Mul};
 
-pub fn part1() -> Result<String, AocError> {
-    let mut monkeys = load_input()?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let mut monkeys = load_input(&input)?;
 
     for _ in 0..20 {
         round(&mut monkeys, true, usize::MAX);
     }
 
     let monkey_business = get_monkey_business(&monkeys);
-    assert_eq!(monkey_business, 121450);
     Ok(format!("{}", monkey_business))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let mut monkeys = load_input()?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let mut monkeys = load_input(&input)?;
 
     let common_multiple = monkeys
         .iter()
@@ -37,8 +36,6 @@ pub fn part2() -> Result<String, AocError> {
 
     let monkey_business = get_monkey_business(&monkeys);
 
-    assert_eq!(monkey_business, 28244037010);
-
     Ok(format!("{}", monkey_business))
 }
 
@@ -1,5 +1,5 @@
 use super::model::{DivisibleBy, Monkey, Operand, Operation, ThrowToMonkey};
-use crate::common::InputType;
+use crate::common::DailyInput;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -175,8 +175,8 @@ mod tests {
     }
 }
 
-pub fn load_input() -> Result<Vec<Monkey>, std::io::Error> {
-    let input = InputType::Challenge.get_input_as_single_string(11)?;
+pub fn load_input(input: &DailyInput) -> Result<Vec<Monkey>, std::io::Error> {
+    let input = input.get_input_as_single_string()?;
 
     match separated_list1(blank_line, monkey)(&input) {
         Ok((remaining, monkeys)) => {
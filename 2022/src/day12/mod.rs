@@ -1,13 +1,11 @@
 use crate::{
-    common::{AocError, InputType, RowCol},
-    day12::search::{Search, SearchState},
+    algo::search::bfs,
+    common::{AocError, DailyInput, RowCol},
     grid::Grid,
 };
 use log::{debug, info};
 use std::collections::HashSet;
 
-mod search;
-
 pub fn get_candidates(
     grid: &Grid,
     from: RowCol,
@@ -39,6 +37,10 @@ pub fn get_candidates(
     result
 }
 
+pub(crate) fn uphill_test(from_elevation: u8, to_elevation: u8) -> bool {
+    to_elevation <= (from_elevation + 1)
+}
+
 pub fn get_elevation(grid: &Grid, rc: RowCol) -> Option<u8> {
     grid.get(rc).map(|re| match re {
         b'S' => b'a',
@@ -47,109 +49,69 @@ pub fn get_elevation(grid: &Grid, rc: RowCol) -> Option<u8> {
     })
 }
 
-pub fn part1() -> Result<String, AocError> {
-    let lines = InputType::Challenge.get_input_lines(12)?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let lines = input.get_input_lines()?;
     let grid = Grid::new(&lines);
     let (start, end) = get_start_and_end(&grid);
 
     info!("Elevations:\n{}", &grid);
 
-    let search = Search::new_uphill_search(&grid, start, end);
-
-    let result = search.find_shortest_path(&None);
-
-    if let Some(path) = result.shortest {
-        debug!("Shortest Path={:?}", path);
-        grid.log_moves_over_dots(log::Level::Info, &path);
-        assert_eq!(path.len() - 1, 380);
-        return Ok(format!("{}", path.len() - 1));
-    }
-    panic!("Unable to find a shortest path")
+    fewest_steps_uphill(&grid, [start], end)
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let lines = InputType::Challenge.get_input_lines(12)?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let lines = input.get_input_lines()?;
     let grid = Grid::new(&lines);
-    let locations = grid.find(HashSet::from(['S' as u8, 'a' as u8, 'E' as u8]));
-    let end = *locations[&('E' as u8)].get(0).unwrap();
-
-    let all_a_elevation_locations: Vec<RowCol> = locations[&('a' as u8)]
-        .iter()
-        .chain(locations[&('S' as u8)].iter())
-        .map(|r| *r)
-        .collect();
-
-    let mut shortest: Option<Vec<RowCol>> = None;
-
-    let mut last_search_result: Option<SearchState> = None;
-    for current_low_spot in &all_a_elevation_locations {
-        debug!("Getting shortest path to low spot {:?}", current_low_spot);
-
-        let search_result = Search::new_uphill_search(&grid, *current_low_spot, end)
-            .find_shortest_path(&last_search_result);
-        if let Some(found) = &search_result.shortest {
-            debug!("    got path of length {:?}", found.len() - 1);
-
-            if let Some(s) = &shortest {
-                if found.len() <= s.len() {
-                    debug!(
-                        "    path of len {} is shorter than shortest of len {}",
-                        found.len() - 1,
-                        s.len() - 1
-                    );
-                    shortest.replace(found.clone());
-                }
-            } else {
-                debug!("    path is first complete path found");
-                shortest.replace(found.clone());
-            }
-        } else {
-            debug!(
-                "    didn't find a path from {:?} to {:?}",
-                current_low_spot, end
-            );
-        }
-        last_search_result.replace(search_result);
-    }
+    let (_, end) = get_start_and_end(&grid);
 
-    debug!("Shortest Path={:?}", shortest);
+    // searching from every low spot at once finds the path from whichever is closest
+    let all_a_elevation_locations = grid
+        .index(HashSet::from([b'S', b'a']))
+        .into_values()
+        .flatten();
+
+    fewest_steps_uphill(&grid, all_a_elevation_locations, end)
+}
 
-    if let Some(path) = shortest {
+fn fewest_steps_uphill(
+    grid: &Grid,
+    starts: impl IntoIterator<Item = RowCol>,
+    end: RowCol,
+) -> Result<String, AocError> {
+    let search = bfs(
+        starts,
+        |rc| get_candidates(grid, *rc, uphill_test),
+        |rc| *rc == end,
+    );
+
+    if let Some(path) = search.path() {
+        debug!("Shortest Path={:?}", path);
         grid.log_moves_over_dots(log::Level::Info, &path);
-        assert_eq!(path.len() - 1, 375);
         return Ok(format!("{}", path.len() - 1));
     }
-    panic!("Unable to find a shortest path");
+    panic!("Unable to find a shortest path")
 }
 
 fn get_start_and_end(grid: &Grid) -> (RowCol, RowCol) {
-    let locations = grid.find(HashSet::from(['E' as u8, 'S' as u8]));
+    let locations = grid.index(HashSet::from([b'E', b'S']));
     (
-        *locations[&('S' as u8)].get(0).unwrap(),
-        *locations[&('E' as u8)].get(0).unwrap(),
+        *locations[&b'S'].first().unwrap(),
+        *locations[&b'E'].first().unwrap(),
     )
 }
 
-// fn get_shortest(grid: &Grid, start: (usize, usize), end: (usize, usize)) -> Option<Path> {
-//     let mut shortest: Option<Path> = None;
-//     let mut path = Path::new();
-//     path.push(start);
-//     search(&grid, &mut shortest, &mut path, &end);
-//     shortest
-// }
-
 #[cfg(test)]
 mod tests {
     use log::info;
 
     use crate::{
-        common::{RowCol, InputType},
-        day12::{get_candidates, get_elevation, get_start_and_end, search::Search, Grid},
+        common::{DailyInput, InputType, RowCol},
+        day12::{get_candidates, get_elevation, get_start_and_end, uphill_test, Grid},
     };
 
     #[test]
     fn test_a() {
-        let lines = InputType::Challenge.get_input_lines(12).unwrap();
+        let lines = DailyInput::new(12, InputType::Challenge).get_input_lines().unwrap();
 
         let grid = Grid::new(&lines);
 
@@ -167,13 +129,13 @@ mod tests {
         assert_eq!(get_elevation(&grid, RowCol::new(29, 45)).unwrap(), b'a');
         assert_eq!(get_elevation(&grid, RowCol::new(29, 46)).unwrap(), b'c');
 
-        let candidates = get_candidates(&grid, (20, 87).into(), Search::uphill_test);
+        let candidates = get_candidates(&grid, (20, 87).into(), uphill_test);
         assert_eq!(candidates.len(), 3);
         assert!(candidates.contains(&(19, 87).into()));
         assert!(candidates.contains(&(21, 87).into()));
         assert!(candidates.contains(&(20, 86).into()));
 
-        let candidates = get_candidates(&grid, (20, 0).into(), Search::uphill_test);
+        let candidates = get_candidates(&grid, (20, 0).into(), uphill_test);
         assert_eq!(candidates.len(), 3);
         assert!(candidates.contains(&(19, 0).into()));
         assert!(candidates.contains(&(20, 1).into()));
@@ -1,4 +1,4 @@
-use crate::common::{blank_line, AocError, InputType};
+use crate::common::{blank_line, AocError, DailyInput};
 use log::{debug, info};
 use nom::{
     branch::alt,
@@ -117,8 +117,8 @@ fn list_list_item(input: &str) -> IResult<&str, ListItem> {
     list(input).map(|(input, parsed)| (input, ListItem::List(parsed)))
 }
 
-fn parse_packet_pairs() -> Result<Vec<(Packet, Packet)>, AocError> {
-    let input = InputType::Challenge.get_input_as_single_string(13)?;
+fn parse_packet_pairs(input: &DailyInput) -> Result<Vec<(Packet, Packet)>, AocError> {
+    let input = input.get_input_as_single_string()?;
     let pairs = match packet_pairs(&input) {
         Ok(pairs) => {
             if !pairs.0.is_empty() {
@@ -148,8 +148,8 @@ fn fix_ordering(pairs: &mut Vec<(Packet, Packet)>) {
     }
 }
 
-pub fn part1() -> Result<String, AocError> {
-    let pairs = parse_packet_pairs()?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let pairs = parse_packet_pairs(&input)?;
 
     info!("There are {} packet pairs", pairs.len());
 
@@ -167,13 +167,11 @@ pub fn part1() -> Result<String, AocError> {
 
     let sum: usize = in_order_indices.iter().sum();
 
-    assert_eq!(sum, 5350);
-
     Ok(format!("{}", sum))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let mut pairs = parse_packet_pairs()?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let mut pairs = parse_packet_pairs(&input)?;
     fix_ordering(&mut pairs);
 
     let mut flattened_packets = Vec::<Packet>::with_capacity(pairs.len() * 2);
@@ -197,8 +195,6 @@ pub fn part2() -> Result<String, AocError> {
 
     let product = divider1_index * divider2_index;
 
-    assert_eq!(product, 19570);
-
     Ok(format!("{}", product))
 }
 
@@ -1,5 +1,5 @@
 use crate::common::AocError;
-use crate::common::InputType;
+use crate::common::DailyInput;
 use crate::common::RowCol;
 use log::debug;
 use nom::bytes::complete::tag;
@@ -28,8 +28,8 @@ pub(crate) fn coordinate_pair(input: &str) -> IResult<&str, RowCol> {
     .map(|(input, parts)| (input, RowCol::new(parts.2, parts.0))) // note XY->YX swap
 }
 
-pub(crate) fn parse_input() -> Result<Vec<PolyLine>, AocError> {
-    let all_input = InputType::Challenge.get_input_as_single_string(14)?;
+pub(crate) fn parse_input(input: &DailyInput) -> Result<Vec<PolyLine>, AocError> {
+    let all_input = input.get_input_as_single_string()?;
     let lines = poly_lines(&all_input);
     debug!("Line definitions: {:?}", lines);
     let vec_lines = match lines {
@@ -1,4 +1,7 @@
-use crate::common::{AocError, InputType, XY};
+use crate::{
+    common::{AocError, DailyInput, XY},
+    intervals::IntervalSet,
+};
 use log::{debug, info};
 use nom::{bytes::complete::tag, multi::separated_list1, sequence::tuple, IResult};
 use std::collections::{BTreeSet, HashMap};
@@ -41,8 +44,8 @@ fn parse_line(input: &str) -> IResult<&str, (Sensor, Beacon)> {
     })
 }
 
-pub fn get_input(input_type: InputType) -> Result<Vec<(Sensor, Beacon)>, AocError> {
-    let input = input_type.get_input_as_single_string(15)?;
+pub fn get_input(input: &DailyInput) -> Result<Vec<(Sensor, Beacon)>, AocError> {
+    let input = input.get_input_as_single_string()?;
     let result = match separated_list1(tag("\n"), parse_line)(&input) {
         Ok((remaining, parsed)) => {
             if !remaining.is_empty() {
@@ -146,30 +149,18 @@ fn count_impossible_beacon_positions_on_line_y(
         let beacon_x_on_line = get_xs_of_beacons_on_line(line_y, &input);
         let sensor_x_on_line = get_xs_of_sensors_on_line(line_y, &input);
 
-        let min_x = intersections
-            .iter()
-            .map(|(_sensor, range)| range.0)
-            .min()
-            .unwrap();
-        let max_x = intersections
-            .iter()
-            .map(|(_sensor, range)| range.1)
-            .max()
-            .unwrap();
-
-        debug!("Range to scan on line y={}: {}..={}", line_y, min_x, max_x);
-
-        for x in min_x..=max_x {
-            let is_x_inside_some_intersection =
-                intersections.iter().any(|e| e.1 .0 <= x && x <= e.1 .1);
-
-            if is_x_inside_some_intersection
-                && !beacon_x_on_line.contains(&x)
-                && !sensor_x_on_line.contains(&x)
-            {
-                result += 1;
-            }
-        }
+        let covered = intersections
+            .values()
+            .map(|(min_x, max_x)| *min_x..=*max_x)
+            .collect::<IntervalSet<_>>();
+        let occupied = beacon_x_on_line
+            .union(&sensor_x_on_line)
+            .map(|x| *x..=*x)
+            .collect::<IntervalSet<_>>();
+
+        debug!("Covered on line y={}: {:?}", line_y, covered.ranges());
+
+        result = covered.difference(&occupied).total_length() as usize;
     }
     result
 }
@@ -223,36 +214,31 @@ fn slope_based_part2_solution(input: &Vec<(Sensor, Beacon)>, limit: i64) -> Opti
     None
 }
 
-pub fn part1() -> Result<String, AocError> {
-    let input = get_input(InputType::Challenge)?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let row = input.param("row", 10, 2000000)?;
+    let input = get_input(&input)?;
 
-    let result = count_impossible_beacon_positions_on_line_y(2000000, &input);
-
-    assert_eq!(result, 5166077);
+    let result = count_impossible_beacon_positions_on_line_y(row, &input);
 
     Ok(format!("{:?}", result))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let input = get_input(InputType::Challenge)?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let limit = input.param("limit", 20, 4000000)?;
+    let input = get_input(&input)?;
 
-    let limit = 4000000;
     let result = slope_based_part2_solution(&input, limit);
 
-    assert_eq!(result, Some(XY::new(3267801, 2703981)));
-
     let result = result.unwrap();
     let result = result.x() * limit + result.y();
 
-    assert_eq!(result, 13071206703981);
-
     Ok(format!("{:?}", result))
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        common::{AocError, InputType},
+        common::{AocError, DailyInput, InputType},
         day15::{count_impossible_beacon_positions_on_line_y, get_input, manhattan_distance},
     };
 
@@ -275,7 +261,7 @@ mod tests {
 
     #[test]
     fn test_example_part_1() -> Result<(), AocError> {
-        let input = get_input(InputType::Example)?;
+        let input = get_input(&DailyInput::new(15, InputType::Example))?;
 
         assert_eq!(count_impossible_beacon_positions_on_line_y(9, &input), 25);
         assert_eq!(count_impossible_beacon_positions_on_line_y(10, &input), 26);
@@ -286,7 +272,7 @@ mod tests {
 
     #[test]
     fn test_example_part_2() -> Result<(), AocError> {
-        let input = get_input(InputType::Example)?;
+        let input = get_input(&DailyInput::new(15, InputType::Example))?;
         slope_based_part2_solution(&input, 20);
         Ok(())
     }
@@ -1,5 +1,5 @@
 
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use nom::bytes::complete::{tag, take};
 use nom::IResult;
 use nom::{branch::alt, multi::separated_list1, sequence::tuple};
@@ -30,8 +30,8 @@ pub(crate) fn parse_line(input: &str) -> IResult<&str, Valve> {
     .map(|(input, parts)| (input, Valve { id: parts.1, flow_rate: parts.3, tunnels: parts.5 }))
 }
 
-pub fn get_input(input_type: InputType) -> Result<Vec<Valve>, AocError> {
-    let input = input_type.get_input_as_single_string(16)?;
+pub fn get_input(input: &DailyInput) -> Result<Vec<Valve>, AocError> {
+    let input = input.get_input_as_single_string()?;
     let result = match separated_list1(tag("\n"), parse_line)(&input) {
         Ok((remaining, parsed)) => {
             if !remaining.is_empty() {
@@ -50,11 +50,14 @@ pub fn get_input(input_type: InputType) -> Result<Vec<Valve>, AocError> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{common::InputType, day16::input::get_input};
+    use crate::{
+        common::{DailyInput, InputType},
+        day16::input::get_input,
+    };
 
     #[test]
     fn parse_example_input() {
-        let input = get_input(InputType::Example).unwrap();
+        let input = get_input(&DailyInput::new(16, InputType::Example)).unwrap();
 
         println!("{:?}", input);
 
@@ -1,23 +1,21 @@
 use log::debug;
 
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use std::collections::HashMap;
 
 use self::input::Valve;
 
 mod input;
 
-pub fn part1() -> Result<String, AocError> {
-    let input = input::get_input(InputType::Challenge)?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let input = input::get_input(&input)?;
     let answer = get_max_flow(&IndexedInput::from(input));
 
-    assert_eq!(1737, answer);
-
     Ok(format!("{:?}", answer))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let input = input::get_input(InputType::Challenge)?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let input = input::get_input(&input)?;
     let indexed = IndexedInput::from(input);
 
     let num_flowable_valves = indexed
@@ -50,8 +48,6 @@ pub fn part2() -> Result<String, AocError> {
         max = max.max(released_a + released_b);
     }
 
-    assert_eq!(max, 2216);
-
     Ok(format!("{max}"))
 }
 
@@ -159,6 +155,8 @@ fn simulate_all_permutations(
 
                     released = released.max(this_plus_prev);
                 }
+                // Moving takes one tunnel per minute, so distances between valves fall out of
+                // the minutes here without a separate `algo::search` pass over the tunnels.
                 for preceding_valve_index in
                     input.valve_tunnel_indexes.get(current_valve_index).unwrap()
                 {
@@ -220,13 +218,13 @@ impl<T> NOfM<T> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        common::InputType,
+        common::{DailyInput, InputType},
         day16::{get_max_flow, input, IndexedInput},
     };
 
     #[test]
     fn test_part1_example_sim() {
-        let input = input::get_input(InputType::Example).unwrap();
+        let input = input::get_input(&DailyInput::new(16, InputType::Example)).unwrap();
 
         let answer = get_max_flow(&IndexedInput::from(input));
 
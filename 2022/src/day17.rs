@@ -2,7 +2,10 @@ use std::{collections::VecDeque, fmt::Display};
 
 use log::{debug, info, trace};
 
-use crate::common::{AocError, InputType};
+use crate::{
+    algo::cycle::{find_cycle, metric_at},
+    common::{AocError, DailyInput},
+};
 
 const SHAPE1: u32 = 0b0000000 << 24 | 0b0000000 << 16 | 0b0000000 << 8 | 0b0011110;
 const SHAPE2: u32 = 0b0000000 << 24 | 0b0001000 << 16 | 0b0011100 << 8 | 0b0001000;
@@ -10,8 +13,12 @@ const SHAPE3: u32 = 0b0000000 << 24 | 0b0000100 << 16 | 0b0000100 << 8 | 0b00111
 const SHAPE4: u32 = 0b0010000 << 24 | 0b0010000 << 16 | 0b0010000 << 8 | 0b0010000;
 const SHAPE5: u32 = 0b0000000 << 24 | 0b0000000 << 16 | 0b0011000 << 8 | 0b0011000;
 
-pub fn part1() -> Result<String, AocError> {
-    let input = InputType::Challenge.get_input_as_single_string(17)?;
+/// How far down from the top of the stack to look when checking if it's been seen before.  Assumed to be further than
+/// any rock can fall.
+const SURFACE_ROWS: usize = 64;
+
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let input = input.get_input_as_single_string()?;
 
     let mut sim = Sim::new(input.chars().collect(), shapes_vec());
 
@@ -21,17 +28,13 @@ pub fn part1() -> Result<String, AocError> {
 
     let top_row = sim.top_rock_row.unwrap() + 1;
 
-    assert_eq!(top_row, 3188);
-
     Ok(format!("{top_row}"))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let input = InputType::Challenge.get_input_as_single_string(17)?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let input = input.get_input_as_single_string()?;
     let result = run_large_sim(&input, 1000000000000_usize);
 
-    assert_eq!(result, 1591977077342);
-
     Ok(format!("{result}"))
 }
 
@@ -39,7 +42,7 @@ fn shapes_vec() -> Vec<u32> {
     vec![SHAPE1, SHAPE2, SHAPE3, SHAPE4, SHAPE5]
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Jet {
     Left,
     Right,
@@ -57,8 +60,10 @@ impl Display for Jet {
     }
 }
 
+#[derive(Clone)]
 struct Sim {
     jets: VecDeque<Jet>,
+    jet_index: usize,
     shapes: VecDeque<u32>,
     stack: VecDeque<u8>,
     shape: u32,
@@ -83,6 +88,7 @@ impl Sim {
 
         Sim {
             jets: my_jets,
+            jet_index: 0,
             shapes: my_shapes,
             stack: VecDeque::from([0; 100000]),
             shape: 0u32,
@@ -100,6 +106,7 @@ impl Sim {
     fn next_jet(&mut self) -> &Jet {
         let next_jet = self.jets.pop_front().unwrap();
         self.jets.push_back(next_jet);
+        self.jet_index = (self.jet_index + 1) % self.jets.len();
         &self.jets[self.jets.len() - 1]
     }
 
@@ -137,6 +144,24 @@ impl Sim {
         }
     }
 
+    fn dropped(mut self) -> Self {
+        self.drop_shape();
+        self
+    }
+
+    fn height(&self) -> usize {
+        self.top_rock_row.map_or(0, |row| row + 1)
+    }
+
+    /// Everything that decides where the next rocks land: the next shape, the next jet and the top of the stack.
+    fn surface(&self) -> (u32, usize, Vec<u8>) {
+        let top = self.height();
+        let rows = (top.saturating_sub(SURFACE_ROWS)..top)
+            .map(|row| self.stack[row])
+            .collect();
+        (*self.shapes.front().unwrap(), self.jet_index, rows)
+    }
+
     fn add_shape(&mut self) {
         self.shape = self.next_shape();
 
@@ -222,150 +247,31 @@ impl Sim {
     }
 }
 
-struct GenerationRepetition {
-    generation_iterations: usize,
-    iteration_heights: Vec<usize>,
-    cycle_start_iteration: usize,
-    cycle_iteration_length: usize,
-}
-
-impl GenerationRepetition {
-    fn find_generation_repetition(sim: &mut Sim) -> GenerationRepetition {
-        let generation_iterations = sim.shapes.len() * sim.jets.len();
-
-        info!(
-            "Will repeat every {} iterations ( {} * {} )",
-            generation_iterations,
-            sim.shapes.len(),
-            sim.jets.len()
-        );
-
-        let mut iteration_heights = Vec::<usize>::new();
-        let mut generation_top_values = Vec::<u8>::new();
-
-        loop {
-            for _ in 0..generation_iterations {
-                sim.drop_shape();
-                iteration_heights.push(sim.top_rock_row.unwrap());
-            }
-            generation_top_values.push(sim.stack[sim.top_rock_row.unwrap()]);
-
-            debug!(
-                "generation = {} repeat iter = {} height: {} : top row = {}",
-                iteration_heights.len() / generation_iterations,
-                iteration_heights.len(),
-                iteration_heights.last().unwrap(),
-                sim.stack[sim.top_rock_row.unwrap()]
-            );
-
-            if repeats(&generation_top_values) {
-                return GenerationRepetition {
-                    generation_iterations,
-                    iteration_heights,
-                    cycle_start_iteration: 1 * generation_iterations,
-                    cycle_iteration_length: (generation_top_values.len()) / 2
-                        * generation_iterations,
-                };
-            }
-        }
-    }
-}
-fn run_large_sim(input: &String, count: usize) -> usize {
-    let mut sim = Sim::new(input.chars().collect(), shapes_vec());
+fn run_large_sim(input: &str, count: usize) -> usize {
+    let sim = Sim::new(input.chars().collect(), shapes_vec());
 
-    match GenerationRepetition::find_generation_repetition(&mut sim) {
-        GenerationRepetition {
-            cycle_start_iteration,
-            cycle_iteration_length,
-            generation_iterations,
-            iteration_heights,
-        } => {
-            let num_cycles = (count - cycle_start_iteration) / cycle_iteration_length;
-            let iterations_spent_in_cycles = cycle_iteration_length * num_cycles;
-            let remaining_iters = count - cycle_start_iteration - iterations_spent_in_cycles;
-
-            let height_of_first_iteration = iteration_heights[generation_iterations - 1];
-            let height_of_full_cycle = iteration_heights
-                [cycle_start_iteration + cycle_iteration_length]
-                - iteration_heights[cycle_start_iteration];
-            let remain_height = iteration_heights[cycle_start_iteration + remaining_iters]
-                - iteration_heights[cycle_start_iteration];
-
-            info!("Cycle start iteration={}", cycle_start_iteration);
-            info!(
-                "Cycle length (in iterations)={} (from iteration {} to {})",
-                cycle_iteration_length,
-                cycle_start_iteration,
-                cycle_start_iteration + cycle_iteration_length
-            );
-            info!(
-                "Num full cycles= ({}-{})/{} = {}",
-                count, cycle_start_iteration, cycle_iteration_length, num_cycles
-            );
-            info!(
-                "Iterations in cycles= {}*{}= {}",
-                cycle_iteration_length, num_cycles, iterations_spent_in_cycles
-            );
-            info!(
-                "Remaining iterations = {}-{}-{}={}",
-                count, cycle_start_iteration, iterations_spent_in_cycles, remaining_iters
-            );
-            info!("Height of 1st iteration = {}", height_of_first_iteration);
-            info!("Height of full iteration cycle = {}", height_of_full_cycle);
-            info!(
-                "Height of {}*{}={}",
-                num_cycles,
-                height_of_full_cycle,
-                num_cycles + height_of_full_cycle
-            );
-            debug!("Height of final iterations = {}", remain_height);
-
-            height_of_first_iteration + num_cycles * height_of_full_cycle + remain_height + 1
-        }
-    }
-}
+    let (cycle_start, cycle_length) = find_cycle(sim.clone(), Sim::dropped, Sim::surface);
+    info!("Rock {cycle_start} starts a cycle of {cycle_length} rocks");
 
-fn repeats(values: &[u8]) -> bool {
-    if values.len() & 1 == 1 {
-        return false;
-    }
-    let half = values.len() / 2;
-    &values[0..half] == &values[half..values.len()]
+    metric_at(
+        sim,
+        Sim::dropped,
+        Sim::height,
+        (cycle_start, cycle_length),
+        count,
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        common::{enable_logging, InputType},
-        day17::{repeats, run_large_sim, shapes_vec, Sim},
+        common::{enable_logging, DailyInput, InputType},
+        day17::{run_large_sim, shapes_vec, Sim},
     };
 
-    #[test]
-    fn test_repeats() {
-        assert_eq!(
-            repeats(&[28, 56, 64, 4, 4, 4, 48, 28, 56, 64, 4, 4, 4, 48]),
-            true
-        );
-        assert_eq!(repeats(&vec![0, 1, 2, 0, 1, 2]), true);
-        assert_eq!(
-            repeats(&vec![0, 0, 0, 1, 2, 1, 2, 0, 0, 0, 1, 2, 1, 2]),
-            true
-        );
-        assert_eq!(repeats(&vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 7]), false);
-        assert_eq!(repeats(&vec![0, 0, 1, 0, 1, 2, 3, 1, 2, 3]), false);
-        assert_eq!(
-            repeats(&vec![9, 2, 3, 1, 0, 0, 5, 6, 1, 2, 3, 0, 0, 5, 6, 1, 2, 3],),
-            false
-        );
-        assert_eq!(
-            repeats(&vec![1, 2, 2, 4, 4, 2, 2, 4, 4, 1, 2, 2, 4, 4, 2, 2, 4, 4],),
-            true
-        );
-    }
-
     #[test]
     fn visualize_example() {
-        let input = InputType::Example.get_input_as_single_string(17).unwrap();
+        let input = DailyInput::new(17, InputType::Example).get_input_as_single_string().unwrap();
 
         let mut sim = Sim::new(input.chars().collect(), shapes_vec());
 
@@ -377,7 +283,7 @@ mod tests {
 
     #[test]
     fn test_part1_example() {
-        let input = InputType::Example.get_input_as_single_string(17).unwrap();
+        let input = DailyInput::new(17, InputType::Example).get_input_as_single_string().unwrap();
 
         let mut sim = Sim::new(input.chars().collect(), shapes_vec());
 
@@ -393,7 +299,7 @@ mod tests {
     fn test_part2_example() {
         enable_logging().unwrap();
 
-        let input = InputType::Example.get_input_as_single_string(17).unwrap();
+        let input = DailyInput::new(17, InputType::Example).get_input_as_single_string().unwrap();
         let result = run_large_sim(&input, 1000000000000_usize);
         assert_eq!(result, 1514285714288_usize);
     }
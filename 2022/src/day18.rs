@@ -1,4 +1,4 @@
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use log::{debug, trace};
 use std::{
     collections::{HashMap, HashSet},
@@ -6,15 +6,13 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-pub fn part1() -> Result<String, AocError> {
-    let area = simple_solve(InputType::Challenge);
-    assert_eq!(area, 4548);
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let area = simple_solve(&input);
     Ok(format!("{area}"))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let area = count_exposed_faces_reachable_from_outside(InputType::Challenge);
-    assert_eq!(area, 2588);
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let area = count_exposed_faces_reachable_from_outside(&input);
     Ok(format!("{area}"))
 }
 
@@ -61,9 +59,9 @@ impl PartialEq for Point {
 }
 impl Eq for Point {}
 
-fn load_cubes(input_type: InputType) -> Result<Vec<Point>, AocError> {
-    Ok(input_type
-        .get_input_lines(18)?
+fn load_cubes(input: &DailyInput) -> Result<Vec<Point>, AocError> {
+    Ok(input
+        .get_input_lines()?
         .iter()
         .map(|l| {
             let values: Vec<f32> = l.split(',').map(|s| s.parse::<f32>().unwrap()).collect();
@@ -87,8 +85,8 @@ fn cube_face_histogram(cubes: &Vec<Point>) -> HashMap<Point, usize> {
     face_counts
 }
 
-fn simple_solve(input_type: InputType) -> usize {
-    let input = load_cubes(input_type).unwrap();
+fn simple_solve(input: &DailyInput) -> usize {
+    let input = load_cubes(input).unwrap();
     trace!("input={:?}", input);
     count_exposed_faces(&input)
 }
@@ -100,8 +98,8 @@ fn count_exposed_faces(input: &Vec<Point>) -> usize {
     histogram.iter().filter(|e| *e.1 == 1).count()
 }
 
-fn count_exposed_faces_reachable_from_outside(input_type: InputType) -> usize {
-    let input = load_cubes(input_type).unwrap();
+fn count_exposed_faces_reachable_from_outside(input: &DailyInput) -> usize {
+    let input = load_cubes(input).unwrap();
     trace!("input={:?}", input);
 
     let mut search = OpenToOutsideSearch::new(&input);
@@ -239,17 +237,17 @@ impl OpenToOutsideSearch {
 #[cfg(test)]
 mod tests {
     use super::{count_exposed_faces_reachable_from_outside, simple_solve};
-    use crate::common::InputType;
+    use crate::common::{DailyInput, InputType};
 
     #[test]
     fn test_part1_example() {
-        let area = simple_solve(InputType::Example);
+        let area = simple_solve(&DailyInput::new(18, InputType::Example));
         assert_eq!(area, 64);
     }
 
     #[test]
     fn test_part2_example() {
-        let area = count_exposed_faces_reachable_from_outside(InputType::Example);
+        let area = count_exposed_faces_reachable_from_outside(&DailyInput::new(18, InputType::Example));
         assert_eq!(area, 58);
     }
 }
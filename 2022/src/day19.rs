@@ -6,19 +6,17 @@ use std::{
 use log::{debug, info};
 use nom::{bytes::complete::tag, multi::separated_list1, sequence::tuple, IResult};
 
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 
-pub fn part1() -> Result<String, AocError> {
-    let blueprints = get_input(InputType::Challenge)?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let blueprints = get_input(&input)?;
     let answer = sum_quality(24, &blueprints);
-    assert_eq!(answer, 1262);
     Ok(format!("{}", answer))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let inputs = get_input(InputType::Challenge)?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let inputs = get_input(&input)?;
     let answer: usize = inputs[0..3].iter().map(|bp| compute(32, &bp).geode() as usize).product();
-    assert_eq!(answer, 37191);
     Ok(format!("{}", answer))
 }
 
@@ -294,8 +292,8 @@ fn parse_blueprint(input: &str) -> IResult<&str, Blueprint> {
     })
 }
 
-fn get_input(input_type: InputType) -> Result<Vec<Blueprint>, AocError> {
-    let input = input_type.get_input_as_single_string(19)?;
+fn get_input(input: &DailyInput) -> Result<Vec<Blueprint>, AocError> {
+    let input = input.get_input_as_single_string()?;
     let result = match separated_list1(tag("\n"), parse_blueprint)(&input) {
         Ok((remaining, parsed)) => {
             if !remaining.is_empty() {
@@ -345,7 +343,7 @@ impl BlueprintTargets {
 #[cfg(test)]
 mod tests {
     use crate::{
-        common::InputType,
+        common::{DailyInput, InputType},
         day19::{compute, get_input, sum_quality},
     };
 
@@ -469,19 +467,19 @@ mod tests {
 
     #[test]
     fn test_parse_challenge_data() {
-        let input = get_input(InputType::Challenge).unwrap();
+        let input = get_input(&DailyInput::new(19, InputType::Challenge)).unwrap();
         assert_eq!(input.len(), 30);
     }
 
     #[test]
     fn test_part1_example() {
-        let input = get_input(InputType::Example).unwrap();
+        let input = get_input(&DailyInput::new(19, InputType::Example)).unwrap();
         assert_eq!(sum_quality(24, &input), 33);
     }
 
     #[test]
     fn test_part2_example() {
-        let input = get_input(InputType::Example).unwrap();
+        let input = get_input(&DailyInput::new(19, InputType::Example)).unwrap();
         assert_eq!(compute(32, &input[0]).geode(), 56);
         assert_eq!(compute(32, &input[1]).geode(), 62);
     }
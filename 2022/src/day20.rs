@@ -1,32 +1,30 @@
-use crate::common::{AocError, InputType};
+use crate::common::{AocError, DailyInput};
 use log::debug;
 use std::collections::VecDeque;
 
-pub fn part1() -> Result<String, AocError> {
-    let numbers = parse_numbers(InputType::Challenge)?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let numbers = parse_numbers(&input)?;
 
     let mixed = mix(&numbers, 1);
     let coord = get_coordinates(&mixed);
     let answer = coord.0 + coord.1 + coord.2;
 
-    assert_eq!(answer, 4914);
     Ok(format!("{}", answer))
 }
 
-pub fn part2() -> Result<String, AocError> {
-    let numbers = parse_numbers(InputType::Challenge)?;
+pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let numbers = parse_numbers(&input)?;
     let numbers = numbers.iter().map(|n| *n * 811589153).collect();
     let mixed = mix(&numbers, 10);
     let coord = get_coordinates(&mixed);
     let answer = coord.0 + coord.1 + coord.2;
 
-    assert_eq!(answer, 7973051839072);
     Ok(format!("{}", answer))
 }
 
-fn parse_numbers(input_type: InputType) -> Result<Vec<i64>, AocError> {
-    Ok(input_type
-        .get_input_lines(20)?
+fn parse_numbers(input: &DailyInput) -> Result<Vec<i64>, AocError> {
+    Ok(input
+        .get_input_lines()?
         .iter()
         .map(|line| line.parse::<i64>().unwrap())
         .collect::<Vec<i64>>())
@@ -78,13 +76,13 @@ fn get_coordinates(mixed: &Vec<i64>) -> (i64, i64, i64) {
 #[cfg(test)]
 mod tests {
     use crate::{
-        common::InputType,
+        common::{DailyInput, InputType},
         day20::{get_coordinates, mix, parse_numbers},
     };
 
     #[test]
     fn test_part1_example() {
-        let numbers = parse_numbers(InputType::Example).unwrap();
+        let numbers = parse_numbers(&DailyInput::new(20, InputType::Example)).unwrap();
 
         let mixed = mix(&numbers, 1);
 
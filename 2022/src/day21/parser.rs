@@ -1,4 +1,4 @@
-use crate::common::{InputType, AocError};
+use crate::common::{DailyInput, AocError};
 use super::model::{Assignment, Expression, Operation};
 use log::debug;
 use nom::{branch::alt, bytes::complete::tag, character::complete::alpha1, sequence::tuple, IResult};
@@ -16,8 +16,8 @@ impl From<&str> for Operation {
     }
 }
 
-pub(super) fn load_inputs(input_type: InputType) -> Result<Vec<Assignment>, AocError> {
-    let lines = input_type.get_input_lines(21)?;
+pub(super) fn load_inputs(input: &DailyInput) -> Result<Vec<Assignment>, AocError> {
+    let lines = input.get_input_lines()?;
 
     Ok(lines
         .iter()
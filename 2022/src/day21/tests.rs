@@ -1,16 +1,16 @@
 use super::{eval::Evaluator, parser::load_inputs, reorg::reorganize_assignments};
-use crate::{common::InputType};
+use crate::common::{DailyInput, InputType};
 
 #[test]
 fn test_part1() {
-    let assignments = load_inputs(InputType::Example).unwrap();
+    let assignments = load_inputs(&DailyInput::new(21, InputType::Example)).unwrap();
     let answer = Evaluator::new(&assignments).evaluate("root");
     assert_eq!(answer, 152);
 }
 
 #[test]
 fn test_part2() {
-    let assignments = load_inputs(InputType::Example).unwrap();
+    let assignments = load_inputs(&DailyInput::new(21, InputType::Example)).unwrap();
 
     println!("==== Original Assignments");
     for assignment in &assignments {
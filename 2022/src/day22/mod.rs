@@ -1,7 +1,7 @@
 use log::{info, debug};
 
 use crate::{
-    common::{AocError, InputType, RowCol},
+    common::{AocError, DailyInput, RowCol},
     grid::Grid,
 };
 
@@ -15,18 +15,16 @@ mod parser;
 #[cfg(test)]
 mod tests;
 
-pub fn part1() -> Result<String, AocError> {
-    let (grid, moves) = load_inputs(InputType::Challenge)?;
+pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let (grid, moves) = load_inputs(&input)?;
     info!("{}", grid);
     let (final_position, final_orientation) = apply_moves(&grid, &moves);
     let answer = password(&final_position, &final_orientation);
 
-    assert_eq!(answer, 57350);
-
     Ok(format!("{}", answer))
 }
 
-pub fn part2() -> Result<String, AocError> {
+pub fn part2(_input: DailyInput) -> Result<String, AocError> {
     Ok(format!("{}", ""))
 }
 
@@ -71,39 +69,16 @@ fn forward(
 }
 
 fn next_position(grid: &Grid, pos: RowCol, orientation: Orientation) -> RowCol {
+    let board = grid.wrapping();
     let mut p = pos;
 
     loop {
-        let possible_next = p.next_position(orientation);
-        match grid.get(possible_next) {
-            Some(c) => {
-                if c == b'.' {
-                    return possible_next;
-                } else if c == b'#' {
-                    return pos;
-                } else if c == b' ' || c == 0 {
-                    p = possible_next
-                } else {
-                    panic!("Unrecognized character {}", c);
-                }
-            }
-            None => {
-                if possible_next.col() < grid.min().col() {
-                    // went off left
-                    p = RowCol::new(possible_next.row(), grid.max().col() + 1);
-                } else if possible_next.col() > grid.max().col() {
-                    // went off right
-                    p = RowCol::new(possible_next.row(), grid.min().col() - 1);
-                } else if possible_next.row() < grid.min().row() {
-                    // went off top
-                    p = RowCol::new(grid.max().row() + 1, possible_next.col());
-                } else if possible_next.row() > grid.max().row() {
-                    // went off bottom
-                    p = RowCol::new(grid.min().row() - 1, possible_next.col());
-                } else {
-                    panic!("Not sure how this happened")
-                }
-            }
+        p = board.wrap(orientation.step_from(p));
+        match board.get(p) {
+            b'.' => return p,
+            b'#' => return pos,
+            b' ' => {}
+            c => panic!("Unrecognized character {}", c),
         }
     }
 }
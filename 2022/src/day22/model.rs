@@ -39,15 +39,12 @@ impl Orientation {
             Self::North => Self::East,
         }
     }
-}
-
-impl RowCol {
-    pub(super) fn next_position(&self, orientation: Orientation) -> RowCol {
-        match orientation {
-            Orientation::North => RowCol::new(self.row() - 1, self.col()),
-            Orientation::South => RowCol::new(self.row() + 1, self.col()),
-            Orientation::East => RowCol::new(self.row(), self.col() + 1),
-            Orientation::West => RowCol::new(self.row(), self.col() - 1),
+    pub(super) fn step_from(&self, rc: RowCol) -> RowCol {
+        match self {
+            Self::North => RowCol::new(rc.row() - 1, rc.col()),
+            Self::South => RowCol::new(rc.row() + 1, rc.col()),
+            Self::East => RowCol::new(rc.row(), rc.col() + 1),
+            Self::West => RowCol::new(rc.row(), rc.col() - 1),
         }
     }
 }
@@ -1,5 +1,5 @@
 use crate::{
-    common::{AocError, InputType, RowCol},
+    common::{AocError, DailyInput, RowCol},
     grid::Grid,
 };
 use log::debug;
@@ -7,12 +7,15 @@ use nom::{branch::alt, bytes::complete::tag, multi::many1, IResult};
 
 use super::model::Move;
 
-pub(super) fn load_inputs(input_type: InputType) -> Result<(Grid, Vec<Move>), AocError> {
-    let mut lines = input_type.get_input_lines(22)?;
-    let move_line = lines.remove(lines.len() - 1);
-    lines.remove(lines.len() - 1);
+pub(super) fn load_inputs(input: &DailyInput) -> Result<(Grid, Vec<Move>), AocError> {
+    let sections = input.sections()?;
+    let [map, move_line] = sections.as_slice() else {
+        return Err(AocError::ParseFailed {
+            message: format!("Expected a map and a path but found {} sections", sections.len()),
+        });
+    };
 
-    let moves = match parse_moves(&move_line) {
+    let moves = match parse_moves(move_line) {
             Ok(a) => match a.0 {
                 "" => a.1,
                 left_over => panic!("Line: {}, Left over text: {:?}", move_line, left_over),
@@ -20,7 +23,9 @@ pub(super) fn load_inputs(input_type: InputType) -> Result<(Grid, Vec<Move>), Ao
             Err(e) => panic!("Line: {}, Error: {:?}", move_line, e),
         };
 
-    Ok((Grid::new_offset(RowCol::new(1, 1), &lines), moves))
+    // rows end where the map does, so anything past the end of a row is off the map, the same as leading spaces
+    let map = Grid::parse_filled(map, b' ')?.with_min(RowCol::new(1, 1));
+    Ok((map, moves))
 }
 
 pub(super) fn parse_moves(input: &str) -> IResult<&str, Vec<Move>> {
@@ -2,13 +2,13 @@ use std::collections::HashMap;
 
 use super::{model::Orientation, parser::load_inputs};
 use crate::{
-    common::{InputType, RowCol},
+    common::{DailyInput, InputType, RowCol},
     day22::{apply_moves, find_start, get_cube_edge_size, password},
 };
 
 #[test]
 fn test_part1() {
-    let (grid, moves) = load_inputs(InputType::Example).unwrap();
+    let (grid, moves) = load_inputs(&DailyInput::new(22, InputType::Example)).unwrap();
 
     println!("Grid:\n{}", grid);
     println!("Moves: {:?}", moves);
@@ -28,7 +28,7 @@ fn test_part1() {
 
 #[test]
 fn test_part2() {
-    let (grid, moves) = load_inputs(InputType::Example).unwrap();
+    let (grid, moves) = load_inputs(&DailyInput::new(22, InputType::Example)).unwrap();
 
     println!("Grid:\n{}", grid);
     println!("Moves: {:?}", moves);
@@ -0,0 +1,93 @@
+mod common;
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+
+use std::path::Path;
+
+use aoc_common::{algo, grid, image, intervals, recorder, registry::Year, sparse_grid};
+pub use aoc_common::{enable_logging, AocError, DayPartFn, PartFn};
+
+pub fn get_day_parts() -> Vec<DayPartFn> {
+    vec![
+        DayPartFn::new(1, 1, day01::part1),
+        DayPartFn::new(1, 2, day01::part2),
+        DayPartFn::new(2, 1, day02::part1),
+        DayPartFn::new(2, 2, day02::part2),
+        DayPartFn::new(3, 1, day03::part1),
+        DayPartFn::new(3, 2, day03::part2),
+        DayPartFn::new(4, 1, day04::part1),
+        DayPartFn::new(4, 2, day04::part2),
+        DayPartFn::new(5, 1, day05::part1),
+        DayPartFn::new(5, 2, day05::part2),
+        DayPartFn::new(6, 1, day06::part1),
+        DayPartFn::new(6, 2, day06::part2),
+        DayPartFn::new(7, 1, day07::part1),
+        DayPartFn::new(7, 2, day07::part2),
+        DayPartFn::new(8, 1, day08::part1),
+        DayPartFn::new(8, 2, day08::part2),
+        DayPartFn::new(9, 1, day09::part1),
+        DayPartFn::new(9, 2, day09::part2),
+        DayPartFn::new(10, 1, day10::part1),
+        DayPartFn::new(10, 2, day10::part2),
+        DayPartFn::new(11, 1, day11::part1),
+        DayPartFn::new(11, 2, day11::part2),
+        DayPartFn::new(12, 1, day12::part1),
+        DayPartFn::new(12, 2, day12::part2),
+        DayPartFn::new(13, 1, day13::part1),
+        DayPartFn::new(13, 2, day13::part2),
+        DayPartFn::new(14, 1, day14::part1),
+        DayPartFn::new(14, 2, day14::part2),
+        DayPartFn::new(15, 1, day15::part1),
+        DayPartFn::new(15, 2, day15::part2),
+        DayPartFn::new(16, 1, day16::part1),
+        DayPartFn::new(16, 2, day16::part2),
+        DayPartFn::new(17, 1, day17::part1),
+        DayPartFn::new(17, 2, day17::part2),
+        DayPartFn::new(18, 1, day18::part1),
+        DayPartFn::new(18, 2, day18::part2),
+        DayPartFn::new(19, 1, day19::part1),
+        DayPartFn::new(19, 2, day19::part2),
+        DayPartFn::new(20, 1, day20::part1),
+        DayPartFn::new(20, 2, day20::part2),
+        DayPartFn::new(21, 1, day21::part1),
+        DayPartFn::new(21, 2, day21::part2),
+        DayPartFn::new(22, 1, day22::part1),
+        DayPartFn::new(22, 2, day22::part2),
+    ]
+}
+
+pub struct Aoc2022;
+
+impl Year for Aoc2022 {
+    fn year(&self) -> usize {
+        2022
+    }
+
+    fn dir(&self) -> &'static Path {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+    }
+
+    fn day_parts(&self) -> Vec<DayPartFn> {
+        get_day_parts()
+    }
+}
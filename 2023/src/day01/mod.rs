@@ -84,28 +84,12 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 1,
-                input_type: InputType::Example,
-                number: Some(1),
-            })
-            .unwrap(),
-            "142"
-        );
+        assert_eq!(part1(DailyInput::numbered(1, InputType::Example, 1)).unwrap(), "142");
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 1,
-                input_type: InputType::Example,
-                number: Some(2),
-            })
-            .unwrap(),
-            "281"
-        );
+        assert_eq!(part2(DailyInput::numbered(1, InputType::Example, 2)).unwrap(), "281");
     }
 
     #[test]
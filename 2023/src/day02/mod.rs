@@ -55,27 +55,11 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 2,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "8"
-        );
+        assert_eq!(part1(DailyInput::new(2, InputType::Example)).unwrap(), "8");
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 2,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "2286"
-        );
+        assert_eq!(part2(DailyInput::new(2, InputType::Example)).unwrap(), "2286");
     }
 }
@@ -125,40 +125,16 @@ mod tests {
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 3,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "4361"
-        );
+        assert_eq!(part1(DailyInput::new(3, InputType::Example)).unwrap(), "4361");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 3,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "514969"
-        );
+        assert_eq!(part1(DailyInput::new(3, InputType::Challenge)).unwrap(), "514969");
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 3,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "467835"
-        );
+        assert_eq!(part2(DailyInput::new(3, InputType::Example)).unwrap(), "467835");
     }
 }
@@ -93,53 +93,21 @@ mod tests {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 4,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "13"
-        );
+        assert_eq!(part1(DailyInput::new(4, InputType::Example)).unwrap(), "13");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 4,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "25174"
-        );
+        assert_eq!(part1(DailyInput::new(4, InputType::Challenge)).unwrap(), "25174");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 4,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "6420979"
-        );
-    }   
+        assert_eq!(part2(DailyInput::new(4, InputType::Challenge)).unwrap(), "6420979");
+    }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 4,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "6420979"
-        );
-    }   
+        assert_eq!(part2(DailyInput::new(4, InputType::Challenge)).unwrap(), "6420979");
+    }
 }
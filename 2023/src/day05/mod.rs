@@ -7,8 +7,9 @@ mod tests;
 pub use part1::part1;
 pub use part2::part2;
 
+use crate::intervals::{IntervalMap, IntervalSet};
 use regex::Regex;
-use std::{collections::HashMap, ops::RangeInclusive};
+use std::{collections::HashMap, ops::Range};
 
 #[derive(Clone, Debug)]
 pub(crate) struct Inputs {
@@ -28,7 +29,7 @@ impl Inputs {
             .unwrap_or_else(|| panic!("Expect to have a mapping from '{}' to '{}'", from, to));
         Mapper {
             stages,
-            numeric_mappings: mappings.to_vec(),
+            offsets: mappings.iter().map(|m| (m.inlet_range.clone(), m.offset)).collect(),
         }
     }
 }
@@ -82,35 +83,17 @@ impl From<&Vec<String>> for Inputs {
     }
 }
 
-#[allow(dead_code)]
 #[derive(Clone, Debug)]
 pub(crate) struct NumericMapping {
-    inlet_range: RangeInclusive<i64>,
-    outlet_range: RangeInclusive<i64>,
+    inlet_range: Range<i64>,
     offset: i64,
 }
 
 impl NumericMapping {
     pub fn new(to: i64, from: i64, size: i64) -> Self {
-        let offset = to - from;
         Self {
-            inlet_range: from..=(from + size - 1),
-            outlet_range: (from + offset)..=(from + size - 1 + offset),
-            offset,
-        }
-    }
-    pub fn map(&self, value: i64) -> Option<i64> {
-        if self.inlet_range.contains(&value) {
-            Some(value + self.offset)
-        } else {
-            None
-        }
-    }
-    pub fn map_reverse(&self, value: i64) -> Option<i64> {
-        if self.outlet_range.contains(&value) {
-            Some(value - self.offset)
-        } else {
-            None
+            inlet_range: from..(from + size),
+            offset: to - from,
         }
     }
 }
@@ -124,31 +107,24 @@ pub(crate) struct StageMapping {
 pub(crate) struct Mapper {
     #[allow(dead_code)]
     stages: StageMapping,
-    numeric_mappings: Vec<NumericMapping>,
+    /// What to add to values in each inlet range.  Values outside all of them stay the same.
+    offsets: IntervalMap<i64, i64>,
 }
 
 impl Mapper {
     pub(crate) fn map(&self, input: i64) -> i64 {
-        for mapping in &self.numeric_mappings {
-            let mapped = mapping.map(input);
-            if let Some(result) = mapped {
-                return result;
-            }
-        }
-        input
-    }
-    pub(crate) fn map_reverse(&self, input: i64) -> i64 {
-        for mapping in &self.numeric_mappings {
-            let mapped = mapping.map_reverse(input);
-            if let Some(result) = mapped {
-                return result;
-            }
-        }
-        input
+        input + self.offsets.get(input).copied().unwrap_or(0)
     }
 
-    fn outlet_ranges(&self) -> Vec<RangeInclusive<i64>> {
-        self.numeric_mappings.iter().map(|nr| nr.outlet_range.clone()).collect()
+    pub(crate) fn map_set(&self, inputs: &IntervalSet<i64>) -> IntervalSet<i64> {
+        self.offsets
+            .segments(inputs)
+            .into_iter()
+            .map(|(range, offset)| {
+                let offset = offset.copied().unwrap_or(0);
+                (range.start + offset)..(range.end + offset)
+            })
+            .collect()
     }
 }
 
@@ -164,12 +140,9 @@ impl Mappings {
         self.mappers.iter().fold(value, |value, mapper| mapper.map(value))
     }
 
-    pub(crate) fn map_reverse(&self, value: i64) -> i64 {
-        self.mappers.iter().rev().fold(value, |value, mapper| mapper.map_reverse(value))
-    }
-
-    pub(crate) fn outlet_ranges(&self) -> Vec<RangeInclusive<i64>> {
-        self.mappers.iter().flat_map(|nms| nms.outlet_ranges()).collect()
+    /// Pushes whole ranges of values through every stage at once, splitting them wherever the mappings do.
+    pub(crate) fn map_set(&self, values: &IntervalSet<i64>) -> IntervalSet<i64> {
+        self.mappers.iter().fold(values.clone(), |values, mapper| mapper.map_set(&values))
     }
 }
 
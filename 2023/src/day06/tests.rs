@@ -19,52 +19,20 @@ fn test_comp_dist() {
 
 #[test]
 fn test_part1_example() {
-    assert_eq!(
-        part1(DailyInput {
-            day: 6,
-            input_type: InputType::Example,
-            number: None,
-        })
-        .unwrap(),
-        "288"
-    );
+    assert_eq!(part1(DailyInput::new(6, InputType::Example)).unwrap(), "288");
 }
 
 #[test]
 fn test_part1_challenge() {
-    assert_eq!(
-        part1(DailyInput {
-            day: 6,
-            input_type: InputType::Challenge,
-            number: None,
-        })
-        .unwrap(),
-        "1083852"
-    );
+    assert_eq!(part1(DailyInput::new(6, InputType::Challenge)).unwrap(), "1083852");
 }
 
 #[test]
 fn test_part2_example() {
-    assert_eq!(
-        part2(DailyInput {
-            day: 6,
-            input_type: InputType::Example,
-            number: None,
-        })
-        .unwrap(),
-        "71503"
-    );
+    assert_eq!(part2(DailyInput::new(6, InputType::Example)).unwrap(), "71503");
 }
 
 #[test]
 fn test_part2_challenge() {
-    assert_eq!(
-        part2(DailyInput {
-            day: 6,
-            input_type: InputType::Challenge,
-            number: None,
-        })
-        .unwrap(),
-        "23501589"
-    );
+    assert_eq!(part2(DailyInput::new(6, InputType::Challenge)).unwrap(), "23501589");
 }
@@ -81,52 +81,26 @@ fn test_hand_ranking() {
 
 #[test]
 fn test_part1_example() {
-    assert_eq!(
-        part1::part1(DailyInput {
-            day: 7,
-            input_type: InputType::Example,
-            number: None,
-        })
-        .unwrap(),
-        "6440"
-    );
+    assert_eq!(part1::part1(DailyInput::new(7, InputType::Example)).unwrap(), "6440");
 }
 
 #[test]
 fn test_part1_challenge() {
     assert_eq!(
-        part1::part1(DailyInput {
-            day: 7,
-            input_type: InputType::Challenge,
-            number: None,
-        })
-        .unwrap(),
+        part1::part1(DailyInput::new(7, InputType::Challenge)).unwrap(),
         "251216224"
     );
 }
 
 #[test]
 fn test_part2_example() {
-    assert_eq!(
-        part2::part2(DailyInput {
-            day: 7,
-            input_type: InputType::Example,
-            number: None,
-        })
-        .unwrap(),
-        "5905"
-    );
+    assert_eq!(part2::part2(DailyInput::new(7, InputType::Example)).unwrap(), "5905");
 }
 
 #[test]
 fn test_part2_challenge() {
     assert_eq!(
-        part2::part2(DailyInput {
-            day: 7,
-            input_type: InputType::Challenge,
-            number: None,
-        })
-        .unwrap(),
+        part2::part2(DailyInput::new(7, InputType::Challenge)).unwrap(),
         "250825971"
     );
 }
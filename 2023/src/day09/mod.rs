@@ -118,53 +118,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 9,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "114"
-        );
+        assert_eq!(part1(DailyInput::new(9, InputType::Example)).unwrap(), "114");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 9,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "1884768153"
-        );
+        assert_eq!(part1(DailyInput::new(9, InputType::Challenge)).unwrap(), "1884768153");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 9,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "2"
-        );
+        assert_eq!(part2(DailyInput::new(9, InputType::Example)).unwrap(), "2");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 9,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "1031"
-        );
+        assert_eq!(part2(DailyInput::new(9, InputType::Challenge)).unwrap(), "1031");
     }
 }
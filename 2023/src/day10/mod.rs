@@ -181,7 +181,7 @@ impl<'a> Iterator for PipeIterator<'a> {
 
 fn find_start(grid: &Grid) -> RowCol {
     let to_find = HashSet::<u8>::from([b'S']);
-    let found = grid.find(to_find);
+    let found = grid.index(to_find);
 
     debug!("Start @ {:?}", found);
     let start_locations = found.get(&b'S').expect("Expected to find 'S' in the grid");
@@ -269,66 +269,26 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 10,
-                input_type: InputType::Example,
-                number: Some(1),
-            })
-            .unwrap(),
-            "4"
-        );
+        assert_eq!(part1(DailyInput::numbered(10, InputType::Example, 1)).unwrap(), "4");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 10,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "6717"
-        );
+        assert_eq!(part1(DailyInput::new(10, InputType::Challenge)).unwrap(), "6717");
     }
 
     #[test]
     fn test_part2_example2() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 10,
-                input_type: InputType::Example,
-                number: Some(2),
-            })
-            .unwrap(),
-            "4"
-        );
+        assert_eq!(part2(DailyInput::numbered(10, InputType::Example, 2)).unwrap(), "4");
     }
 
     #[test]
     fn test_part2_example3() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 10,
-                input_type: InputType::Example,
-                number: Some(3),
-            })
-            .unwrap(),
-            "8"
-        );
+        assert_eq!(part2(DailyInput::numbered(10, InputType::Example, 3)).unwrap(), "8");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 10,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "381"
-        );
+        assert_eq!(part2(DailyInput::new(10, InputType::Challenge)).unwrap(), "381");
     }
 }
@@ -3,13 +3,31 @@ use std::collections::HashSet;
 use crate::{grid::Grid, AocError, DailyInput, RowCol};
 
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
-    calc(input, 1)
+    let mut grid = Grid::new(&input.get_input_lines()?);
+
+    // small enough to expand the universe for real
+    for row in empty_rows(&grid).into_iter().rev() {
+        grid.insert_row_after(row);
+    }
+    for col in empty_cols(&grid).into_iter().rev() {
+        grid.insert_col_after(col);
+    }
+
+    let galaxies = grid.cells_matching(|&c| c == b'#').map(|(rc, _)| rc).collect::<Vec<_>>();
+    let sum: usize = (0..galaxies.len())
+        .flat_map(|a| (a + 1..galaxies.len()).map(move |b| (a, b)))
+        .map(|(a, b)| shortest_path(galaxies[a], galaxies[b], &[], &[], 0))
+        .sum();
+
+    Ok(sum.to_string())
 }
 
 pub fn part2(input: DailyInput) -> Result<String, AocError> {
     calc(input, 1000000-1)
 }
 
+/// Galaxies sit in open space, so the shortest path is the Manhattan distance plus the expansion of every empty row
+/// and column it crosses.  No need for `algo::search`, which couldn't walk a million-times expanded universe anyway.
 fn shortest_path(
     start: RowCol,
     end: RowCol,
@@ -34,19 +52,27 @@ fn shortest_path(
     base_distance + (crossed_cols_to_expand + crossed_rows_to_expand) * expand_by
 }
 
+fn empty_rows(grid: &Grid) -> Vec<i64> {
+    grid.rows().filter(|&row| grid.get_row(row).unwrap().all(|v| v == b'.')).collect()
+}
+
+fn empty_cols(grid: &Grid) -> Vec<i64> {
+    grid.cols().filter(|&col| grid.get_col(col).unwrap().all(|v| v == b'.')).collect()
+}
+
 pub fn calc(input: DailyInput, expand_by: usize) -> Result<String, AocError> {
     let lines = input.get_input_lines()?;
     let grid = Grid::new(&lines);
 
 //    println!("Grid: {grid}");
 
-    let rows_to_expand = grid.rows().filter(|&row| grid.get_row(row).unwrap().all(|v| v == b'.')).collect::<Vec<_>>();
+    let rows_to_expand = empty_rows(&grid);
     // println!("rows to expand {:?}", rows_to_expand);
 
-    let cols_to_expand = grid.cols().filter(|&col| grid.get_col(col).unwrap().all(|v| v == b'.')).collect::<Vec<_>>();
+    let cols_to_expand = empty_cols(&grid);
     // println!("cols to expand {:?}", cols_to_expand);
 
-    let galaxies = grid.find(HashSet::from([b'#']));
+    let galaxies = grid.index(HashSet::from([b'#']));
     let found = galaxies.get(&b'#').expect("Should have found some #s");
     // println!("Galaxies found at {:?}", found);
     let sum: usize = (0..found.len())
@@ -66,37 +92,17 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 11,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "374"
-        );
+        assert_eq!(part1(DailyInput::new(11, InputType::Example)).unwrap(), "374");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 11,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "9974721"
-        );
+        assert_eq!(part1(DailyInput::new(11, InputType::Challenge)).unwrap(), "9974721");
     }
 
     #[test]
     fn test_part2_example() {
-        let input = DailyInput {
-            day: 11,
-            input_type: InputType::Example,
-            number: None,
-        };
+        let input = DailyInput::new(11, InputType::Example);
         assert_eq!(calc(input.clone(), 10-1).unwrap(), "1030");
         assert_eq!(calc(input, 100-1).unwrap(), "8410");
     }
@@ -104,12 +110,7 @@ mod test {
     #[test]
     fn test_part2_challenge() {
         assert_eq!(
-            part2(DailyInput {
-                day: 11,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
+            part2(DailyInput::new(11, InputType::Challenge)).unwrap(),
             "702770569197"
         );
     }
@@ -165,52 +165,23 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 12,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "21"
-        );
+        assert_eq!(part1(DailyInput::new(12, InputType::Example)).unwrap(), "21");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 12,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "7939"
-        );
+        assert_eq!(part1(DailyInput::new(12, InputType::Challenge)).unwrap(), "7939");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 12,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "525152"
-        );
+        assert_eq!(part2(DailyInput::new(12, InputType::Example)).unwrap(), "525152");
     }
 
     #[test]
     fn test_part2_challenge() {
         assert_eq!(
-            part2(DailyInput {
-                day: 12,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
+            part2(DailyInput::new(12, InputType::Challenge)).unwrap(),
             "850504257483930"
         );
     }
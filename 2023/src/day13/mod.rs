@@ -1,4 +1,6 @@
-use crate::{grid::Grid, AocError, DailyInput, RowCol};
+use std::ops::RangeInclusive;
+
+use crate::{grid::Grid, AocError, DailyInput};
 
 #[derive(PartialEq, Debug, Clone, Copy, Hash, Eq, PartialOrd, Ord)]
 pub(crate) struct MirrorLine {
@@ -13,44 +15,48 @@ pub(crate) enum MirrorLineMatch {
     Horizontal(MirrorLine),
 }
 
+/// Finds where folding the grid between two rows lines up everything that overlaps, except for exactly
+/// `num_differences_allowed` cells.  `differences` counts the mismatches between two rows, or two cols when looking for
+/// a vertical line.
+fn find_mirror_line(
+    range: RangeInclusive<i64>,
+    num_differences_allowed: usize,
+    differences: impl Fn(i64, i64) -> usize,
+) -> Option<MirrorLine> {
+    let (min, max) = (*range.start(), *range.end());
+    (min..max)
+        .find(|&before| {
+            let num_to_compare = (before - min + 1).min(max - before);
+            (0..num_to_compare)
+                .map(|i| differences(before - i, before + 1 + i))
+                .sum::<usize>()
+                == num_differences_allowed
+        })
+        .map(|before| MirrorLine {
+            before,
+            after: before + 1,
+            num_before: before - min + 1,
+        })
+}
+
 pub(crate) fn find_h_mirror_line(grid: &Grid, num_differences_allowed: usize) -> Option<MirrorLine> {
-    for row in grid.min_row()..grid.max_row() {
-        let num_to_compare = (row - grid.min_row() + 1).min(grid.max_row() - row);
-        // println!("   num to compare = {num_to_compare}");
-        let before_range = row - num_to_compare + 1..=row;
-        let after_range = row + 1..=row + num_to_compare;
-
-        // println!("Comparing ranges {:?} and {:?}", before_range, after_range);
-        let above = before_range.rev().flat_map(|r| grid.get_row(r).unwrap().rev()).collect::<Vec<_>>();
-        let below = after_range.flat_map(|r| grid.get_row(r).unwrap().rev()).collect::<Vec<_>>();
-        // println!(" CMP {num_to_compare} around {row}:{}", row+1);
-        // println!("      {:?} ", above.iter().map(|d| d.as_char()).collect::<String>());
-        // println!("      {:?} ", below.iter().map(|d| d.as_char()).collect::<String>());
-        if above.iter().zip(below).filter(|&(&a, b)| a != b).count() == num_differences_allowed {
-            return Some(MirrorLine {
-                before: row,
-                after: row + 1,
-                num_before: row - grid.min_row() + 1,
-            });
-        }
-    }
-    None
+    find_mirror_line(grid.rows(), num_differences_allowed, |a, b| grid.row_differences(a, b))
+}
+
+pub(crate) fn find_v_mirror_line(grid: &Grid, num_differences_allowed: usize) -> Option<MirrorLine> {
+    find_mirror_line(grid.cols(), num_differences_allowed, |a, b| grid.col_differences(a, b))
 }
 
 pub(crate) fn find_all_mirror_lines_btree(grid: &Grid, num_differences_allowed: usize) -> Option<MirrorLineMatch> {
     find_h_mirror_line(grid, num_differences_allowed)
         .map(MirrorLineMatch::Horizontal)
-        .or_else(|| find_h_mirror_line(&grid.transpose(), num_differences_allowed).map(MirrorLineMatch::Vertical))
+        .or_else(|| find_v_mirror_line(grid, num_differences_allowed).map(MirrorLineMatch::Vertical))
 }
 
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
-    let lines = input.get_input_lines()?;
-    let lines_to_left_and_above: i64 = lines
-        .split(|line| line.is_empty())
-        .map(Vec::from)
-        .map(|lines_vec| Grid::new_offset(RowCol::new(1, 1), &lines_vec))
-        .enumerate()
-        .map(|(_index, grid)| find_all_mirror_lines_btree(&grid, 0))
+    let lines_to_left_and_above: i64 = Grid::parse_many(&input.get_input_as_single_string()?)?
+        .iter()
+        .map(|grid| find_all_mirror_lines_btree(grid, 0))
         .map(|mlms| mlms.expect("No mirror lines found"))
         .map(|mlm| match mlm {
             MirrorLineMatch::Vertical(mlm) => mlm.num_before,
@@ -64,13 +70,9 @@ pub fn part1(input: DailyInput) -> Result<String, AocError> {
 }
 
 pub fn part2(input: DailyInput) -> Result<String, AocError> {
-    let lines = input.get_input_lines()?;
-    let lines_to_left_and_above: i64 = lines
-        .split(|line| line.is_empty())
-        .map(Vec::from)
-        .map(|lines_vec| Grid::new_offset(RowCol::new(1, 1), &lines_vec))
-        .enumerate()
-        .map(|(_index, grid)| find_all_mirror_lines_btree(&grid, 1))
+    let lines_to_left_and_above: i64 = Grid::parse_many(&input.get_input_as_single_string()?)?
+        .iter()
+        .map(|grid| find_all_mirror_lines_btree(grid, 1))
         .map(|mlms| mlms.expect("No mirror lines found"))
         .map(|mlm| match mlm {
             MirrorLineMatch::Vertical(mlm) => mlm.num_before,
@@ -92,53 +94,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 13,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "405"
-        );
+        assert_eq!(part1(DailyInput::new(13, InputType::Example)).unwrap(), "405");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 13,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "40006"
-        );
+        assert_eq!(part1(DailyInput::new(13, InputType::Challenge)).unwrap(), "40006");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 13,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "400"
-        );
+        assert_eq!(part2(DailyInput::new(13, InputType::Example)).unwrap(), "400");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 13,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "28627"
-        );
+        assert_eq!(part2(DailyInput::new(13, InputType::Challenge)).unwrap(), "28627");
     }
 }
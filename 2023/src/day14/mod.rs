@@ -1,55 +1,12 @@
-use std::{
-    cmp::Ordering,
-    collections::{hash_map::DefaultHasher, HashMap, HashSet},
-    hash::Hasher,
-};
-
+use aoc_common::algo::cycle::{find_cycle, state_at};
 use log::debug;
 
 use crate::{grid::Grid, AocError, DailyInput, RowCol};
 
-enum Direction {
-    N,
-    S,
-    E,
-    W,
-}
-impl Direction {
-    fn step(&self, rc: RowCol) -> RowCol {
-        match self {
-            Direction::E => rc.plus_col(),
-            Direction::S => rc.plus_row(),
-            Direction::W => rc.minus_col(),
-            Direction::N => rc.minus_row(),
-        }
-    }
-
-    fn cmp(&self, a: &RowCol, b: &RowCol) -> Ordering {
-        match self {
-            Direction::E => match b.col().cmp(&a.col()) {
-                Ordering::Equal => a.row().cmp(&b.row()),
-                cmp => cmp,
-            },
-            Direction::S => match b.row().cmp(&a.row()) {
-                Ordering::Equal => a.col().cmp(&b.col()),
-                cmp => cmp,
-            },
-            Direction::W => match a.col().cmp(&b.col()) {
-                Ordering::Equal => a.row().cmp(&b.row()),
-                cmp => cmp,
-            },
-            Direction::N => match a.row().cmp(&b.row()) {
-                Ordering::Equal => a.col().cmp(&b.col()),
-                cmp => cmp,
-            },
-        }
-    }
-}
-
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
     let mut grid = Grid::new(&input.get_input_lines()?);
 
-    tip(&mut grid, Direction::N);
+    tip_north(&mut grid);
 
     let sum = load(&grid);
 
@@ -66,82 +23,40 @@ fn load(grid: &Grid) -> usize {
     sum
 }
 
-fn tip(grid: &mut Grid, direction: Direction) {
-    let found = grid.find(HashSet::from([b'O']));
-    let o_s = found.get(&b'O').expect("Expected there to be some O's");
-    let mut o_s = o_s.clone();
-    o_s.sort_by(|a, b| direction.cmp(a, b));
-    for rc in o_s {
-        let mut o_s_rc: RowCol = rc;
-        'inner: loop {
-            let next_rc = direction.step(o_s_rc);
-            if let Some(next) = grid.get(next_rc) {
-                if next == b'.' {
-                    grid.set(next_rc, b'O');
-                    grid.set(o_s_rc, b'.');
-                    o_s_rc = next_rc;
-                    continue 'inner;
+/// Rolls every O as far north as it'll go.  Other directions are tipped by rotating the grid first.
+fn tip_north(grid: &mut Grid) {
+    for col in grid.cols() {
+        let mut resting_row = grid.min_row();
+        for row in grid.rows() {
+            match grid.get(RowCol::new(row, col)) {
+                Some(b'#') => resting_row = row + 1,
+                Some(b'O') => {
+                    grid.swap(RowCol::new(row, col), RowCol::new(resting_row, col)).unwrap();
+                    resting_row += 1;
                 }
+                _ => {}
             }
-            break 'inner;
         }
     }
 }
 
-fn hash(grid: &Grid) -> u64 {
-    let mut hasher = DefaultHasher::new();
-    grid.hash(&mut hasher);
-    hasher.finish()
+/// Tips north, west, south then east.  Rotating clockwise after each tip brings the next direction to the top, and
+/// the fourth rotation brings the grid back around the way it started.
+fn spin_cycle(grid: Grid) -> Grid {
+    (0..4).fold(grid, |mut grid, _| {
+        tip_north(&mut grid);
+        grid.rotate_cw()
+    })
 }
 
 pub fn part2(input: DailyInput) -> Result<String, AocError> {
-    input.get_input_as_single_string()?;
-
-    let mut grid = Grid::new(&input.get_input_lines()?);
-
-    let mut grid_hashes = HashMap::<u64, Vec<usize>>::new();
+    let grid = Grid::new(&input.get_input_lines()?);
 
-    grid_hashes.entry(hash(&grid)).or_default().push(0);
+    let (cycle_start, cycle_size) = find_cycle(grid.clone(), spin_cycle, Grid::clone);
+    debug!("Spin cycle {cycle_start} is the start of a cycle of {cycle_size}");
 
-    let mut grids = vec![grid.clone()];
-
-    debug!("Grid 0:\n{}", grids[0]);
-
-    let (cycle_start, cycle_end) = 'block: {
-        for index in 1..=1000000000 {
-            tip(&mut grid, Direction::N);
-            tip(&mut grid, Direction::W);
-            tip(&mut grid, Direction::S);
-            tip(&mut grid, Direction::E);
-
-            grids.push(grid.clone());
-
-            let hash_indexes = grid_hashes.entry(hash(&grid)).or_default();
-            hash_indexes.push(index);
-
-            if hash_indexes.len() > 1 {
-                debug!("found cycle between {:?}", hash_indexes);
-                break 'block (hash_indexes[0], hash_indexes[1]);
-            }
-        }
-        todo!();
-    };
-
-    let remaining = 1000000000 - cycle_end;
-    let cycle_size = cycle_end - cycle_start;
-    let div = remaining.div_euclid(cycle_size);
-    let mult = cycle_size * (div + 1);
-    let rem = remaining.rem_euclid(mult);
-    debug!("remaining={remaining}");
-    debug!("div={div}");
-    debug!("mult={mult}");
-    debug!("rem={rem}");
-    debug!("Cycle {:?}", (cycle_start, cycle_end));
-    for (i, g) in grids.iter().enumerate() {
-        debug!("Grid {} load: {}", i, load(g));
-    }
-    let sum = load(&grids[cycle_start + remaining.rem_euclid(cycle_size)]);
-    Ok(sum.to_string())
+    let grid = state_at(grid, spin_cycle, (cycle_start, cycle_size), 1000000000);
+    Ok(load(&grid).to_string())
 }
 
 #[cfg(test)]
@@ -158,53 +73,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 14,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "136"
-        );
+        assert_eq!(part1(DailyInput::new(14, InputType::Example)).unwrap(), "136");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 14,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "110565"
-        );
+        assert_eq!(part1(DailyInput::new(14, InputType::Challenge)).unwrap(), "110565");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 14,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "64"
-        );
+        assert_eq!(part2(DailyInput::new(14, InputType::Example)).unwrap(), "64");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 14,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "89845"
-        );
+        assert_eq!(part2(DailyInput::new(14, InputType::Challenge)).unwrap(), "89845");
     }
 }
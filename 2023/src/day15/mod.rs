@@ -173,53 +173,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 15,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "1320"
-        );
+        assert_eq!(part1(DailyInput::new(15, InputType::Example)).unwrap(), "1320");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 15,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "494980"
-        );
+        assert_eq!(part1(DailyInput::new(15, InputType::Challenge)).unwrap(), "494980");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 15,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "145"
-        );
+        assert_eq!(part2(DailyInput::new(15, InputType::Example)).unwrap(), "145");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 15,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "247933"
-        );
+        assert_eq!(part2(DailyInput::new(15, InputType::Challenge)).unwrap(), "247933");
     }
 }
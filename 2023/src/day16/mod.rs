@@ -1,11 +1,8 @@
-use std::{
-    collections::{HashSet, VecDeque},
-    fmt::Display,
-};
+use std::{collections::VecDeque, fmt::Display};
 
 use log::debug;
 
-use crate::{grid::Grid, AocError, DailyInput, RowCol};
+use crate::{bit_grid::BitGrid, grid::Grid, AocError, DailyInput, RowCol};
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum Direction {
@@ -71,7 +68,8 @@ pub fn part1(input: DailyInput) -> Result<String, AocError> {
 }
 
 fn energy_from(grid: &Grid, start: Beam) -> usize {
-    let mut beams_set: HashSet<Beam> = HashSet::new();
+    // a layer for each direction a beam can pass through a cell
+    let mut beams_set = vec![BitGrid::new(grid.min(), grid.max()); 4];
     let mut beams: VecDeque<Beam> = VecDeque::new();
 
     beams.push_back(start);
@@ -82,7 +80,7 @@ fn energy_from(grid: &Grid, start: Beam) -> usize {
             Some(character) => {
                 debug!("  {b} is on grid");
 
-                if beams_set.insert(b) {
+                if beams_set[b.1 as usize].insert(b.0) {
                     debug!("    {b} is new");
 
                     let next = try_reflect(b, character);
@@ -96,10 +94,12 @@ fn energy_from(grid: &Grid, start: Beam) -> usize {
             None => debug!("  {b} is off grid"),
         }
     }
-    debug!("beams set: {:?}", beams_set);
 
-    let answer = beams_set.iter().map(|b| b.0).collect::<HashSet<_>>().len();
-    answer
+    let mut energized = BitGrid::new(grid.min(), grid.max());
+    beams_set.iter().for_each(|layer| energized |= layer);
+    debug!("energized:\n{energized}");
+
+    energized.count_ones()
 }
 
 pub fn part2(input: DailyInput) -> Result<String, AocError> {
@@ -154,53 +154,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 16,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "46"
-        );
+        assert_eq!(part1(DailyInput::new(16, InputType::Example)).unwrap(), "46");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 16,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "8539"
-        );
+        assert_eq!(part1(DailyInput::new(16, InputType::Challenge)).unwrap(), "8539");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 16,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "51"
-        );
+        assert_eq!(part2(DailyInput::new(16, InputType::Example)).unwrap(), "51");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 16,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "8674"
-        );
+        assert_eq!(part2(DailyInput::new(16, InputType::Challenge)).unwrap(), "8674");
     }
 }
@@ -25,53 +25,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 17,
-                input_type: InputType::Example,
-                number: None,
-            })
-                .unwrap(),
-            "102"
-        );
+        assert_eq!(part1(DailyInput::new(17, InputType::Example)).unwrap(), "102");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 17,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-                .unwrap(),
-            ""
-        );
+        assert_eq!(part1(DailyInput::new(17, InputType::Challenge)).unwrap(), "");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 17,
-                input_type: InputType::Example,
-                number: None,
-            })
-                .unwrap(),
-            ""
-        );
+        assert_eq!(part2(DailyInput::new(17, InputType::Example)).unwrap(), "");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 17,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-                .unwrap(),
-            ""
-        );
+        assert_eq!(part2(DailyInput::new(17, InputType::Challenge)).unwrap(), "");
     }
 }
@@ -1,6 +1,6 @@
 use log::debug;
 
-use crate::{get_num_interior_points, AocError, DailyInput, RowCol, XY};
+use crate::{get_num_interior_points, image::write_svg, AocError, DailyInput, RowCol, XY};
 
 #[derive(Debug)]
 enum Direction {
@@ -38,11 +38,7 @@ pub fn part1(input: DailyInput) -> Result<String, AocError> {
             .map(|line| parse(line))
             .map(|(direction, distance, _color)| (direction, distance)),
     );
-    let trench_xys = trench_rcs.iter().map(|&rc| XY::from(rc)).collect::<Vec<_>>();
-    let picks = get_num_interior_points(&trench_xys);
-    debug!(" {picks}");
-    let total = picks.num_interior_points + picks.num_boundary_points;
-    Ok(total.to_string())
+    lagoon_size(&input, &trench_rcs)
 }
 
 pub fn part2(input: DailyInput) -> Result<String, AocError> {
@@ -62,7 +58,15 @@ pub fn part2(input: DailyInput) -> Result<String, AocError> {
             (direction, distance)
         }),
     );
+    lagoon_size(&input, &trench_rcs)
+}
+
+/// Pass `--param svg=<file>` to see the trench drawn out.
+fn lagoon_size(input: &DailyInput, trench_rcs: &[RowCol]) -> Result<String, AocError> {
     let trench_xys = trench_rcs.iter().map(|&rc| XY::from(rc)).collect::<Vec<_>>();
+    if let Some(svg) = input.params.get::<String>("svg")? {
+        write_svg(svg, std::slice::from_ref(&trench_xys))?;
+    }
     let picks = get_num_interior_points(&trench_xys);
     debug!(" {picks}");
     let total = picks.num_interior_points + picks.num_boundary_points;
@@ -99,52 +103,23 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 18,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "62"
-        );
+        assert_eq!(part1(DailyInput::new(18, InputType::Example)).unwrap(), "62");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 18,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "40714"
-        );
+        assert_eq!(part1(DailyInput::new(18, InputType::Challenge)).unwrap(), "40714");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: 18,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "952408144115"
-        );
+        assert_eq!(part2(DailyInput::new(18, InputType::Example)).unwrap(), "952408144115");
     }
 
     #[test]
     fn test_part2_challenge() {
         assert_eq!(
-            part2(DailyInput {
-                day: 18,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
+            part2(DailyInput::new(18, InputType::Challenge)).unwrap(),
             "129849166997110"
         );
     }
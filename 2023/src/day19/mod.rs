@@ -42,27 +42,16 @@ pub fn part2(input: DailyInput) -> Result<String, AocError> {
     while let Some((workflow_name, mut ranges)) = actions.pop() {
         let workflow = sys.keyed_workflows.get(workflow_name.as_str()).unwrap();
 
-        let mut inverse_ranges = ranges;
         for branch in &workflow.branches {
-            match &branch.predicate {
-                model::Predicate::Always => (),
-                model::Predicate::LessThan { prop, value } => {
-                    ranges.reduce_range_to_below(prop, *value);
-                    inverse_ranges.reduce_range_to_above(prop, ranges.get(prop).1);
-                }
-                model::Predicate::GreaterThan { prop, value } => {
-                    ranges.reduce_range_to_above(prop, *value);
-                    inverse_ranges.reduce_range_to_below(prop, ranges.get(prop).0);
-                }
-            };
+            let (matching, rest) = ranges.split(&branch.predicate);
             // were the branch predicate to evaluate to true, we'd do this
             match &branch.action {
-                model::Action::RunWorkflow(workflow_name) => actions.push((workflow_name.to_string(), ranges)),
+                model::Action::RunWorkflow(workflow_name) => actions.push((workflow_name.to_string(), matching)),
                 model::Action::Reject => (),
-                model::Action::Accept => combinations += ranges.combinations(),
+                model::Action::Accept => combinations += matching.combinations(),
             }
-            // and if the branch predicate evaluages to false, we'd continue down the predicate chain with the inverse ranges
-            ranges = inverse_ranges;
+            // and if the branch predicate evaluages to false, we'd continue down the predicate chain with the rest
+            ranges = rest;
         }
     }
 
@@ -78,39 +67,18 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 19,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "19114"
-        );
+        assert_eq!(part1(DailyInput::new(19, InputType::Example)).unwrap(), "19114");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: 19,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "348378"
-        );
+        assert_eq!(part1(DailyInput::new(19, InputType::Challenge)).unwrap(), "348378");
     }
 
     #[test]
     fn test_part2_example() {
         assert_eq!(
-            part2(DailyInput {
-                day: 19,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
+            part2(DailyInput::new(19, InputType::Example)).unwrap(),
             "167409079868000"
         );
     }
@@ -118,12 +86,7 @@ mod test {
     #[test]
     fn test_part2_challenge() {
         assert_eq!(
-            part2(DailyInput {
-                day: 19,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
+            part2(DailyInput::new(19, InputType::Challenge)).unwrap(),
             "121158073425385"
         );
     }
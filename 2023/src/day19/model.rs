@@ -1,3 +1,4 @@
+use crate::intervals::IntervalSet;
 use std::collections::HashMap;
 
 #[derive(Debug)]
@@ -111,15 +112,15 @@ impl From<&str> for Action {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(super) struct PartRanges {
-    pub x: (i64, i64),
-    pub m: (i64, i64),
-    pub a: (i64, i64),
-    pub s: (i64, i64),
+    pub x: IntervalSet<i64>,
+    pub m: IntervalSet<i64>,
+    pub a: IntervalSet<i64>,
+    pub s: IntervalSet<i64>,
 }
 impl PartRanges {
-    pub fn get(&self, prop: &Prop) -> &(i64, i64) {
+    pub fn get(&self, prop: &Prop) -> &IntervalSet<i64> {
         match prop {
             Prop::X => &self.x,
             Prop::M => &self.m,
@@ -127,7 +128,7 @@ impl PartRanges {
             Prop::S => &self.s,
         }
     }
-    pub fn get_mut(&mut self, prop: &Prop) -> &mut (i64, i64) {
+    pub fn get_mut(&mut self, prop: &Prop) -> &mut IntervalSet<i64> {
         match prop {
             Prop::X => &mut self.x,
             Prop::M => &mut self.m,
@@ -136,29 +137,37 @@ impl PartRanges {
         }
     }
     pub fn combinations(&self) -> i64 {
-        Self::size(self.x) * Self::size(self.m) * Self::size(self.a) * Self::size(self.s)
-    }
-    fn size(range: (i64, i64)) -> i64 {
-        (range.1 - range.0).abs() + 1
+        self.x.total_length() * self.m.total_length() * self.a.total_length() * self.s.total_length()
     }
 
-    pub(crate) fn reduce_range_to_below(&mut self, prop: &Prop, value: i64) {
-        let range = self.get_mut(prop);
-        range.1 = value - 1;
+    /// The parts of these ranges the predicate is true for, and the parts it isn't.
+    pub(crate) fn split(&self, predicate: &Predicate) -> (Self, Self) {
+        match predicate {
+            Predicate::Always => (self.clone(), self.with(&Prop::X, IntervalSet::new())),
+            Predicate::LessThan { prop, value } => {
+                let (below, rest) = self.get(prop).split_at(*value);
+                (self.with(prop, below), self.with(prop, rest))
+            }
+            Predicate::GreaterThan { prop, value } => {
+                let (rest, above) = self.get(prop).split_at(*value + 1);
+                (self.with(prop, above), self.with(prop, rest))
+            }
+        }
     }
 
-    pub(crate) fn reduce_range_to_above(&mut self, prop: &Prop, value: i64) {
-        let range = self.get_mut(prop);
-        range.0 = value + 1;
+    fn with(&self, prop: &Prop, range: IntervalSet<i64>) -> Self {
+        let mut ranges = self.clone();
+        *ranges.get_mut(prop) = range;
+        ranges
     }
 }
 impl Default for PartRanges {
     fn default() -> Self {
         Self {
-            x: (1_i64, 4000),
-            m: (1_i64, 4000),
-            a: (1_i64, 4000),
-            s: (1_i64, 4000),
+            x: IntervalSet::from(1..=4000),
+            m: IntervalSet::from(1..=4000),
+            a: IntervalSet::from(1..=4000),
+            s: IntervalSet::from(1..=4000),
         }
     }
 }
@@ -1,4 +1,15 @@
-use crate::{AocError,DailyInput};
+use crate::{grid::Grid, AocError, DailyInput, RowCol};
+
+/// The garden and where the elf starts in it.  The map tiles endlessly in every direction, so walk it through
+/// [Grid::tiled].
+#[allow(dead_code)]
+fn load_garden(input: &DailyInput) -> Result<(Grid, RowCol), AocError> {
+    let garden = Grid::parse(&input.get_input_as_single_string()?)?;
+    let start = garden.find(b'S').ok_or_else(|| AocError::ParseFailed {
+        message: "There's no starting position".to_string(),
+    })?;
+    Ok((garden, start))
+}
 
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
     input.get_input_as_single_string()?;
@@ -9,3 +20,40 @@ pub fn part2(input: DailyInput) -> Result<String, AocError> {
     input.get_input_as_single_string()?;
     Ok("".to_string())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{day21::load_garden, AocError, DailyInput, RowCol};
+
+    #[test]
+    fn test_load_garden() {
+        let (garden, start) = load_garden(&DailyInput::from_str(21, "#..\n.S.\n..#\n")).unwrap();
+        assert_eq!(start, RowCol::new(1, 1));
+
+        let tiled = garden.tiled();
+        let edge = RowCol::new(2, 1);
+        assert_eq!(
+            tiled.neighbors4(edge).collect::<Vec<_>>(),
+            vec![
+                (RowCol::new(1, 1), b'S'),
+                (RowCol::new(3, 1), b'.'),
+                (RowCol::new(2, 2), b'#'),
+                (RowCol::new(2, 0), b'.')
+            ]
+        );
+        assert_eq!(tiled.tile(RowCol::new(3, 1)), RowCol::new(1, 0));
+        assert_eq!(tiled.get(RowCol::new(-3, 3)), b'#');
+    }
+
+    #[test]
+    fn test_bad_garden() {
+        assert!(matches!(
+            load_garden(&DailyInput::from_str(21, "")),
+            Err(AocError::ParseFailed { .. })
+        ));
+        assert!(matches!(
+            load_garden(&DailyInput::from_str(21, "...\n.#.\n")),
+            Err(AocError::ParseFailed { .. })
+        ));
+    }
+}
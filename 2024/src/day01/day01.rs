@@ -56,53 +56,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "11"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "11");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "2000468"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "2000468");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "31"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "31");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "18567089"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "18567089");
     }
 }
@@ -68,53 +68,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "2"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "2");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "230"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "230");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "4"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "4");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "301"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "301");
     }
 }
@@ -46,53 +46,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: Some(1),
-            })
-            .unwrap(),
-            "161"
-        );
+        assert_eq!(part1(DailyInput::numbered(DAY, InputType::Example, 1)).unwrap(), "161");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "174336360"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "174336360");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: Some(2),
-            })
-            .unwrap(),
-            "48"
-        );
+        assert_eq!(part2(DailyInput::numbered(DAY, InputType::Example, 2)).unwrap(), "48");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "88802350"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "88802350");
     }
 }
@@ -6,10 +6,7 @@ use crate::{
 use strum::IntoEnumIterator;
 
 fn is_xmas_match(grid: &Grid, rc: RowCol, direction: Direction) -> bool {
-    grid.get(rc) == Some(b'X')
-        && grid.get(rc.plus_n(&direction, 1)) == Some(b'M')
-        && grid.get(rc.plus_n(&direction, 2)) == Some(b'A')
-        && grid.get(rc.plus_n(&direction, 3)) == Some(b'S')
+    grid.get(rc) == Some(b'X') && grid.ray(rc, direction).map(|(_, b)| b).take(3).eq(*b"MAS")
 }
 
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
@@ -30,9 +27,8 @@ pub fn part1(input: DailyInput) -> Result<String, AocError> {
 
 fn is_x_mas_match(grid: &Grid, rc: RowCol) -> bool {
     if grid.get(rc) == Some(b'A') {
-        let is_mas = |d1: Direction, d2: Direction| {
-            grid.get(rc.plus(&d1)) == Some(b'M') && grid.get(rc.plus(&d2)) == Some(b'S')
-        };
+        let is_mas =
+            |d1: Direction, d2: Direction| grid.get(rc.plus(&d1)) == Some(b'M') && grid.get(rc.plus(&d2)) == Some(b'S');
 
         (is_mas(Direction::NW, Direction::SE) || is_mas(Direction::SE, Direction::NW))
             && (is_mas(Direction::NE, Direction::SW) || is_mas(Direction::SW, Direction::NE))
@@ -64,53 +60,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "18"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "18");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "2397"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "2397");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "9"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "9");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "1824"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "1824");
     }
 }
@@ -122,53 +122,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "143"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "143");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "4766"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "4766");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "123"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "123");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "6257"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "6257");
     }
 }
@@ -1,8 +1,11 @@
 use std::collections::{BTreeSet, HashSet};
 
 use crate::{
+    bit_grid::BitGrid,
     coord::{Direction, RowCol},
     grid::Grid,
+    image::{Palette, RED},
+    recorder::FrameRecorder,
     AocError, DailyInput,
 };
 
@@ -21,14 +24,16 @@ struct Simulation {
     location: RowCol,
     direction: Direction,
     path: Vec<(RowCol, Direction)>,
-    index: HashSet<(RowCol, Direction)>,
+    /// Where the guard has been, with a layer for each direction they were facing
+    index: Vec<BitGrid>,
 }
 impl Simulation {
     fn start(mut grid: Grid) -> Self {
         let (location, direction) = starting_location(&grid);
         grid.set(location, b'.');
         let path = vec![(location, direction)];
-        let index = HashSet::from([(location, direction)]);
+        let mut index = vec![BitGrid::new(grid.min(), grid.max()); 8];
+        index[direction as usize].insert(location);
         Simulation {
             grid,
             start_location: location,
@@ -50,7 +55,7 @@ impl Simulation {
             None => State::OffGrid,
             Some(b'.') => {
                 let next_entry = (next, self.direction);
-                if !self.index.insert(next_entry) {
+                if !self.visit(next_entry) {
                     return State::LoopDetected;
                 }
                 self.location = next;
@@ -59,7 +64,7 @@ impl Simulation {
             }
             Some(b'#') | Some(b'O') => {
                 let next_entry = (self.location, self.direction.turn_cw_90());
-                if !self.index.insert(next_entry) {
+                if !self.visit(next_entry) {
                     return State::LoopDetected;
                 }
                 self.direction = next_entry.1;
@@ -69,12 +74,16 @@ impl Simulation {
             Some(c) => panic!("Unknown cell {} at location {next}", c as char),
         }
     }
+    /// Returns whether the guard hasn't been here facing this way before.
+    fn visit(&mut self, (location, direction): (RowCol, Direction)) -> bool {
+        self.index[direction as usize].insert(location)
+    }
     fn back(&mut self) -> bool {
         let last = match self.path.pop() {
             Some(x) => x,
             None => return false,
         };
-        self.index.remove(&last);
+        self.index[last.1 as usize].set(last.0, false);
         let tail = match self.path.last() {
             Some(x) => x,
             None => return false,
@@ -83,6 +92,13 @@ impl Simulation {
         self.direction = tail.1;
         true
     }
+    /// The grid with where the guard's been marked `X` and the guard drawn facing the way they're going.
+    fn snapshot(&self) -> Grid {
+        let mut grid = self.grid.clone();
+        self.path.iter().for_each(|(location, _)| grid.set(*location, b'X'));
+        grid.set(self.location, char::from(self.direction) as u8);
+        grid
+    }
     fn run_to_end(&mut self) -> State {
         loop {
             match self.advance() {
@@ -101,7 +117,14 @@ fn starting_location(grid: &Grid) -> (RowCol, Direction) {
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
     let g = Grid::new(&input.get_input_lines()?);
     let mut s = Simulation::start(g);
-    s.run_to_end();
+    let palette = b"^>v<".iter().fold(Palette::default().with(b'X', [255, 200, 200]), |p, guard| p.with(*guard, RED));
+    let mut recorder = FrameRecorder::from_input(&input)?.with_palette(palette);
+    recorder.record_with(|| s.snapshot());
+    while s.advance() == State::Ready {
+        recorder.record_with(|| s.snapshot());
+    }
+    recorder.record_final_with(|| s.snapshot());
+    recorder.finish()?;
 
     let answer = s.path.iter().map(|(l, _)| l).collect::<HashSet<_>>().len();
     Ok(answer.to_string())
@@ -139,7 +162,7 @@ pub fn part2(input: DailyInput) -> Result<String, AocError> {
                 continue;
             }
             Some(b'.') => {
-                if s.index.iter().any(|(location, _)| location == &in_front_location) {
+                if s.index.iter().any(|layer| layer.get(in_front_location) == Some(true)) {
                     // We went through that block in front of us to get here.  Putting an O there would not allow us to get here on the path we did
                 } else {
                     
@@ -169,53 +192,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "41"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "41");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "5404"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "5404");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "6"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "6");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "1984"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "1984");
     }
 }
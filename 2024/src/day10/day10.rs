@@ -1,10 +1,6 @@
 use std::collections::HashSet;
 
-use crate::{
-    coord::{Direction, RowCol},
-    grid::Grid,
-    AocError, DailyInput,
-};
+use crate::{coord::RowCol, grid::Grid, AocError, DailyInput};
 
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
     let (g, trailheads) = grid_and_trailheads(input)?;
@@ -20,7 +16,7 @@ pub fn part1(input: DailyInput) -> Result<String, AocError> {
         })
         .sum::<usize>();
 
-        Ok(answer.to_string())
+    Ok(answer.to_string())
 }
 
 pub fn part2(input: DailyInput) -> Result<String, AocError> {
@@ -41,15 +37,12 @@ pub fn part2(input: DailyInput) -> Result<String, AocError> {
 }
 
 fn climb_to_all_summits(g: &Grid, last_val: u8, coord: &RowCol, report_summit: &mut impl FnMut(&RowCol)) {
-    for dir in [Direction::N, Direction::S, Direction::E, Direction::W] {
-        let next = coord.plus(&dir);
-        if let Some(v) = g.get(next) {
-            if v == last_val + 1 {
-                if v == b'9' {
-                    report_summit(&next);
-                } else {
-                    climb_to_all_summits(g, v, &next, report_summit);
-                }
+    for (next, v) in g.neighbors4(*coord) {
+        if v == last_val + 1 {
+            if v == b'9' {
+                report_summit(&next);
+            } else {
+                climb_to_all_summits(g, v, &next, report_summit);
             }
         }
     }
@@ -71,53 +64,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "36"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "36");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "512"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "512");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "81"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "81");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "1045"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "1045");
     }
 }
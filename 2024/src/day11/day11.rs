@@ -44,10 +44,7 @@ pub fn part1(input: DailyInput) -> Result<String, AocError> {
         .split(' ')
         .map(|s| s.parse::<i64>().unwrap())
         .collect::<Vec<_>>();
-    let times = match input.input_type {
-        crate::InputType::Example => 6,
-        crate::InputType::Challenge => 25,
-    };
+    let times = input.param("blinks", 6, 25)?;
     Ok(solve(stones, times).to_string())
 }
 
@@ -57,10 +54,7 @@ pub fn part2(input: DailyInput) -> Result<String, AocError> {
         .map(|s| s.parse::<i64>().unwrap())
         .collect::<Vec<_>>();
 
-    let times = match input.input_type {
-        crate::InputType::Example => 6,
-        crate::InputType::Challenge => 75,
-    };
+    let times = input.param("blinks", 6, 75)?;
 
     Ok(solve(stones, times).to_string())
 }
@@ -74,52 +68,23 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "22"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "22");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "188902"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "188902");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "22"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "22");
     }
 
     #[test]
     fn test_part2_challenge() {
         assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
+            part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(),
             "223894720281135"
         );
     }
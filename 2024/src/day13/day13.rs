@@ -1,4 +1,7 @@
-use crate::{algo::line_intersection, AocError, DailyInput};
+use crate::{
+    algo::linear::{extended_gcd, solve_2x2, Solution},
+    AocError, DailyInput,
+};
 use recap::Recap;
 use serde::Deserialize;
 
@@ -23,17 +26,62 @@ struct Machine {
     prize: Prize,
 }
 impl Machine {
+    /// The number of times to press A and B to get to the prize for the fewest tokens, if it can be reached at all.
     fn solution(&self) -> Option<(i64, i64)> {
-        line_intersection(
-            self.button_a.x as f64,
-            self.button_b.x as f64,
-            (-self.prize.x) as f64,
-            self.button_a.y as f64,
-            self.button_b.y as f64,
-            (-self.prize.y) as f64,
-        )
+        let (a, b, prize) = (self.button_a, self.button_b, self.prize);
+        let solution = solve_2x2(
+            [[a.x as i128, b.x as i128], [a.y as i128, b.y as i128]],
+            [prize.x as i128, prize.y as i128],
+        );
+        match solution {
+            Solution::Unique(_) => solution.integers().map(|presses| (presses[0] as i64, presses[1] as i64)),
+            Solution::Inconsistent => None,
+            // Both buttons move the claw along the same line the prize is on, so either equation says it all.
+            Solution::Infinite => match (a.x, b.x, prize.x) {
+                (0, 0, _) => cheapest_presses(a.y, b.y, prize.y),
+                _ => cheapest_presses(a.x, b.x, prize.x),
+            },
+        }
+    }
+}
+
+/// The cheapest non-negative whole `(a, b)` with `a * a_step + b * b_step == target`, where A costs 3 tokens and B 1.
+///
+/// Every solution is `(a0 + k * b_step / g, b0 - k * a_step / g)` for some `k`, given one solution `(a0, b0)` from
+/// the extended gcd.  The cost changes by the same amount with each step of `k`, so the cheapest is at whichever end
+/// of the range of `k` that keeps both presses non-negative it's heading towards.
+fn cheapest_presses(a_step: i64, b_step: i64, target: i64) -> Option<(i64, i64)> {
+    let (a_step, b_step, target) = (a_step as i128, b_step as i128, target as i128);
+    let (g, x, y) = extended_gcd(a_step, b_step);
+    if g == 0 {
+        return (target == 0).then_some((0, 0));
     }
+    if target % g != 0 {
+        return None;
+    }
+    let (a0, b0) = (x * (target / g), y * (target / g));
+    let (a_per_k, b_per_k) = (b_step / g, a_step / g);
+
+    // a0 + k * a_per_k >= 0 and b0 - k * b_per_k >= 0
+    let lowest_k = match a_per_k {
+        0 if a0 < 0 => return None,
+        0 => None,
+        _ => Some(-(a0.div_euclid(a_per_k))),
+    };
+    let highest_k = match b_per_k {
+        0 if b0 < 0 => return None,
+        0 => None,
+        _ => Some(b0.div_euclid(b_per_k)),
+    };
+    let k = match (3 * a_per_k - b_per_k > 0, lowest_k, highest_k) {
+        (_, Some(lowest), Some(highest)) if lowest > highest => return None,
+        (true, Some(lowest), _) => lowest,
+        (false, _, Some(highest)) => highest,
+        (_, lowest, highest) => lowest.or(highest)?,
+    };
+    Some(((a0 + k * a_per_k) as i64, (b0 - k * b_per_k) as i64))
 }
+
 fn parse(input: &DailyInput) -> Result<Vec<Machine>, AocError> {
     let lines = input.get_input_lines()?;
     let mut i = lines.iter();
@@ -87,59 +135,60 @@ pub fn part2(input: DailyInput) -> Result<String, AocError> {
 
 #[cfg(test)]
 mod test {
-    use super::{part1, part2};
+    use super::{part1, part2, Button, Machine, Prize};
     use crate::{DailyInput, InputType};
 
     const DAY: usize = 13;
 
+    fn machine(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> Machine {
+        Machine {
+            button_a: Button { x: a.0, y: a.1 },
+            button_b: Button { x: b.0, y: b.1 },
+            prize: Prize { x: prize.0, y: prize.1 },
+        }
+    }
+
     #[test]
-    fn test_part1_example() {
+    fn test_collinear_buttons() {
+        // B goes further for its token than A does for its three
+        assert_eq!(machine((2, 4), (3, 6), (12, 24)).solution(), Some((0, 4)));
+        // A goes more than three times as far as B
+        assert_eq!(machine((6, 6), (1, 1), (12, 12)).solution(), Some((2, 0)));
+        assert_eq!(machine((6, 6), (1, 1), (13, 13)).solution(), Some((2, 1)));
+        // Only 2 + 2 + 3 gets to 7 with steps of 2 and 3
+        assert_eq!(machine((0, 2), (0, 3), (0, 7)).solution(), Some((2, 1)));
+        // Steps of 4 and 6 never land on an odd number
+        assert_eq!(machine((4, 4), (6, 6), (5, 5)).solution(), None);
+        // The prize isn't on the line the buttons move along
+        assert_eq!(machine((1, 1), (2, 2), (3, 4)).solution(), None);
+        // Too small a step for either button to reach without going backwards
+        assert_eq!(machine((5, 5), (7, 7), (3, 3)).solution(), None);
+        assert_eq!(machine((0, 0), (0, 0), (0, 0)).solution(), Some((0, 0)));
         assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "480"
+            machine((3, 3), (5, 5), (10000000000001, 10000000000001)).solution(),
+            Some((2, 2000000000000 - 1))
         );
     }
 
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "480");
+    }
+
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "35255"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "35255");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "875318608908"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "875318608908");
     }
 
     #[test]
     fn test_part2_challenge() {
         assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
+            part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(),
             "87582154060429"
         );
     }
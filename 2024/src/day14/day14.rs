@@ -1,12 +1,11 @@
-use std::collections::BTreeMap;
-
-use itertools::Itertools;
 use regex::Regex;
 
 use crate::{
     as_i64,
     coord::{Offset, RowCol, XY},
     grid::Grid,
+    image::Palette,
+    recorder::FrameRecorder,
     AocError, DailyInput,
 };
 
@@ -37,14 +36,14 @@ struct Robot {
 }
 
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
+    let width = input.param("width", 11, 101)?;
+    let height = input.param("height", 7, 103)?;
     let lines = input.get_input_lines()?;
-    let v = lines[0].split(',').take(2).map(|s| s.parse::<i64>().unwrap()).collect::<Vec<_>>();
-    let grid = Grid::new_repeating(RowCol(0, 0), RowCol(v[0] - 1, v[1] - 1), b'.').transpose();
+    let grid = Grid::new_repeating(RowCol(0, 0), RowCol(width - 1, height - 1), b'.').transpose();
     eprintln!("{} {}", grid.min(), grid.max());
     let re = Regex::new(r"p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)").unwrap();
     let mut robots = lines
         .iter()
-        .skip(1)
         .map(|l| {
             let c = re.captures(l).unwrap();
             let x = as_i64(&c, 1);
@@ -72,21 +71,28 @@ pub fn part1(input: DailyInput) -> Result<String, AocError> {
     eprintln!("Initial");
     print_counts_as_xy(&grid, &robots);
 
+    let arena = grid.wrapping();
+    let mut recorder = FrameRecorder::from_input(&input)?;
+    recorder.record_with(|| picture(&grid, &robots));
     (0..100).for_each(|i| {
         //eprintln!("Step {}", i + 1);
         for robot in &mut robots {
-            let next_row = (robot.location.row() + robot.velocity.row()).rem_euclid(grid.max_row() + 1);
-            let next_col = (robot.location.col() + robot.velocity.col()).rem_euclid(grid.max_col() + 1);
-            robot.location = RowCol(next_row, next_col);
+            robot.location = arena.wrap(robot.location.offset(robot.velocity.row(), robot.velocity.col()));
         }
+        recorder.record_with(|| picture(&grid, &robots));
         if i == 0 {
             eprintln!("After one step:");
             print_counts_as_xy(&grid, &robots);
         }
     });
+    recorder.finish()?;
 
     eprintln!("Final");
     print_counts_as_xy(&grid, &robots);
+    // pass `--param png=<file>` to see them
+    if let Some(png) = input.params.get::<String>("png")? {
+        picture(&grid, &robots).write_png(png, &Palette::default())?;
+    }
 
     let middle_row = (grid.max_row() + grid.min_row()) / 2;
     let middle_col = (grid.max_col() + grid.min_col()) / 2;
@@ -111,27 +117,36 @@ pub fn part1(input: DailyInput) -> Result<String, AocError> {
     Ok(answer.to_string())
 }
 
+/// Where the robots are, as `#`s.
+fn picture(grid: &Grid, robots: &[Robot]) -> Grid {
+    let mut picture = grid.clone();
+    robots.iter().for_each(|robot| picture.set(robot.location, b'#'));
+    picture
+}
+
 fn print_counts_as_xy(grid: &Grid, robots: &[Robot]) {
-    let binding = robots.iter().into_group_map_by(|r| r.location);
-    let counts = binding.iter().map(|(k, v)| (k, v.len())).collect::<BTreeMap<_, _>>();
-    // eprintln!("{counts:?}");
-    let mut gc = grid.clone();
-    for (&rc, c) in counts {
-        gc.set(rc, c.to_string().chars().next().unwrap() as u8);
+    let mut counts = grid.map(|_| 0_usize);
+    for robot in robots {
+        *counts.get_mut(robot.location).unwrap() += 1;
     }
+    // eprintln!("{counts:?}");
+    let gc = grid.zip(&counts, |&b, &count| match count {
+        0 => b,
+        count => count.to_string().chars().next().unwrap() as u8,
+    });
     // let gc = gc.transpose();
     eprintln!("{gc}");
 }
 
 pub fn part2(input: DailyInput) -> Result<String, AocError> {
+    let width = input.param("width", 11, 101)?;
+    let height = input.param("height", 7, 103)?;
     let lines = input.get_input_lines()?;
-    let v = lines[0].split(',').take(2).map(|s| s.parse::<i64>().unwrap()).collect::<Vec<_>>();
-    let grid = Grid::new_repeating(RowCol(0, 0), RowCol(v[0] - 1, v[1] - 1), b'.').transpose();
+    let grid = Grid::new_repeating(RowCol(0, 0), RowCol(width - 1, height - 1), b'.').transpose();
     eprintln!("{} {}", grid.min(), grid.max());
     let re = Regex::new(r"p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)").unwrap();
     let mut robots = lines
         .iter()
-        .skip(1)
         .map(|l| {
             let c = re.captures(l).unwrap();
             let x = as_i64(&c, 1);
@@ -159,12 +174,11 @@ pub fn part2(input: DailyInput) -> Result<String, AocError> {
     eprintln!("Initial");
     print_counts_as_xy(&grid, &robots);
 
+    let arena = grid.wrapping();
     (0..20000).for_each(|i| {
         //eprintln!("Step {}", i + 1);
         for robot in &mut robots {
-            let next_row = (robot.location.row() + robot.velocity.row()).rem_euclid(grid.max_row() + 1);
-            let next_col = (robot.location.col() + robot.velocity.col()).rem_euclid(grid.max_col() + 1);
-            robot.location = RowCol(next_row, next_col);
+            robot.location = arena.wrap(robot.location.offset(robot.velocity.row(), robot.velocity.col()));
         }
         // if i == 0 {
             eprintln!("####### {i} #######");
@@ -193,53 +207,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "12"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "12");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "230436441"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "230436441");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            ""
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            ""
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "");
     }
 }
@@ -1,4 +1,29 @@
-use crate::{AocError, DailyInput};
+use crate::{coord::Direction, grid::Grid, AocError, DailyInput};
+
+/// The warehouse map, then a blank line, then the robot's moves, which can be split over any number of lines.
+#[allow(dead_code)]
+fn load_warehouse(input: &DailyInput) -> Result<(Grid, Vec<Direction>), AocError> {
+    let sections = input.sections()?;
+    let [map, moves] = sections.as_slice() else {
+        return Err(AocError::ParseFailed {
+            message: format!("Expected a map and moves but found {} sections", sections.len()),
+        });
+    };
+    let moves = moves
+        .bytes()
+        .filter(|b| !b.is_ascii_whitespace())
+        .map(|b| match b {
+            b'^' => Ok(Direction::N),
+            b'v' => Ok(Direction::S),
+            b'>' => Ok(Direction::E),
+            b'<' => Ok(Direction::W),
+            _ => Err(AocError::ParseFailed {
+                message: format!("Unknown move {}", b as char),
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((Grid::parse(map)?, moves))
+}
 
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
 input.get_input_lines()?;
@@ -11,72 +36,47 @@ pub fn part2(_input: DailyInput) -> Result<String, AocError> {
 
 #[cfg(test)]
 mod test {
-    use super::{part1, part2};
-    use crate::{DailyInput, InputType};
+    use super::{load_warehouse, part1, part2};
+    use crate::{
+        coord::{Direction, RowCol},
+        DailyInput, InputType,
+    };
 
     const DAY: usize = 15;
 
+    #[test]
+    fn test_load_warehouse() {
+        let (warehouse, moves) = load_warehouse(&DailyInput::numbered(DAY, InputType::Example, 1)).unwrap();
+        assert_eq!((warehouse.row_count(), warehouse.col_count()), (10, 10));
+        assert_eq!(warehouse.find(b'@'), Some(RowCol::new(4, 4)));
+        assert_eq!(moves.len(), 700);
+        assert_eq!(moves[..4], [Direction::W, Direction::S, Direction::S, Direction::E]);
+    }
+
     #[test]
     fn test_part1_example1() {
         assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: Some(1),
-            })
-            .unwrap(),
+            part1(DailyInput::numbered(DAY, InputType::Example, 1)).unwrap(),
             "10092"
         );
     }
     #[test]
     fn test_part1_example2() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: Some(2),
-            })
-            .unwrap(),
-            "2028"
-        );
+        assert_eq!(part1(DailyInput::numbered(DAY, InputType::Example, 2)).unwrap(), "2028");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            ""
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            ""
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            ""
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "");
     }
 }
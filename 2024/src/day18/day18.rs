@@ -1,41 +1,36 @@
-use std::{
-    cmp::Reverse,
-    collections::{BTreeMap, BinaryHeap},
-};
+use std::collections::BTreeMap;
 
 use regex::Regex;
 
 use crate::{
+    algo::search::astar,
     as_i64,
     coord::{RowCol, NSEW, XY},
     grid::Grid,
+    sparse_grid::SparseGrid,
     AocError, DailyInput,
 };
 
 fn get_input(input: DailyInput) -> Result<(Grid, usize, Vec<XY>), AocError> {
     let re = Regex::new(r"^(\d+),(\d+)").unwrap();
 
+    let num_positions = input.param("num_positions", 12, 1024)?;
     let lines = input.get_input_lines()?;
-    let num_positions = lines[0].parse::<usize>().unwrap();
     let byte_positions = lines
         .iter()
-        .skip(1)
         .map(|l| {
             let c = re.captures(l).unwrap();
             XY(as_i64(&c, 1), as_i64(&c, 2))
         })
         .collect::<Vec<_>>();
 
-    let min_x = byte_positions.iter().map(|xy| xy.x()).min().unwrap();
-    let max_x = byte_positions.iter().map(|xy| xy.x()).max().unwrap();
-    let min_y = byte_positions.iter().map(|xy| xy.y()).min().unwrap();
-    let max_y = byte_positions.iter().map(|xy| xy.y()).max().unwrap();
+    let memory_space = byte_positions
+        .iter()
+        .map(|xy| (RowCol::from(*xy), b'.'))
+        .collect::<SparseGrid>()
+        .to_grid(b'.');
 
-    Ok((
-        Grid::new_repeating(XY(min_x, min_y).into(), XY(max_x, max_y).into(), b'.'),
-        num_positions,
-        byte_positions,
-    ))
+    Ok((memory_space, num_positions, byte_positions))
 }
 
 pub fn part1(input: DailyInput) -> Result<String, AocError> {
@@ -56,35 +51,18 @@ pub fn part1(input: DailyInput) -> Result<String, AocError> {
 }
 
 fn shortest_path_num_steps(grid: &Grid, start: RowCol, end: RowCol) -> Option<usize> {
-    let mut min_steps = BTreeMap::<RowCol, usize>::new();
-    grid.all_cell_locations_by_row_by_col().for_each(|location| {
-        min_steps.insert(location, usize::MAX);
-    });
-
-    let mut heap: BinaryHeap<Reverse<(usize, RowCol)>> = BinaryHeap::new();
-    heap.push(Reverse((0, start)));
-    while let Some(Reverse((steps, location))) = heap.pop() {
-        NSEW.iter().for_each(|direction| {
-            let next = location.plus(direction);
-            if let Some(b'.') = grid.get(next) {
-                if let Some(existing) = min_steps.get(&next) {
-                    if steps + 1 < *existing {
-                        min_steps.insert(next, steps + 1);
-                        if next != end {
-                            heap.push(Reverse((steps + 1, next)));
-                        } else {
-                            // eprintln!("Got to end with {} steps", steps + 1);
-                        }
-                    }
-                }
-            }
-        });
-    }
-
-    match *min_steps.get(&end).unwrap() {
-        usize::MAX => None,
-        steps => Some(steps),
-    }
+    let search = astar(
+        [start],
+        |&location| {
+            NSEW.iter()
+                .map(move |direction| location.plus(direction))
+                .filter(|next| grid.get(*next) == Some(b'.'))
+                .map(|next| (next, 1))
+        },
+        |location| location.manhattan_distance(&end) as usize,
+        |location| *location == end,
+    );
+    search.cost()
 }
 
 pub fn part2(input: DailyInput) -> Result<String, AocError> {
@@ -147,53 +125,21 @@ mod test {
 
     #[test]
     fn test_part1_example() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "22"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Example)).unwrap(), "22");
     }
 
     #[test]
     fn test_part1_challenge() {
-        assert_eq!(
-            part1(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "408"
-        );
+        assert_eq!(part1(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "408");
     }
 
     #[test]
     fn test_part2_example() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Example,
-                number: None,
-            })
-            .unwrap(),
-            "6,1"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Example)).unwrap(), "6,1");
     }
 
     #[test]
     fn test_part2_challenge() {
-        assert_eq!(
-            part2(DailyInput {
-                day: DAY,
-                input_type: InputType::Challenge,
-                number: None,
-            })
-            .unwrap(),
-            "45,16"
-        );
+        assert_eq!(part2(DailyInput::new(DAY, InputType::Challenge)).unwrap(), "45,16");
     }
 }
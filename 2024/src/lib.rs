@@ -1,17 +1,10 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Error, Read},
-};
+use std::path::Path;
 
-use flexi_logger::{Logger, LoggerHandle};
 use regex::Captures;
 use seq_macro::seq;
-use thiserror::Error;
 
-mod algo;
-mod coord;
-mod grid;
-mod parse;
+pub use aoc_common::{enable_logging, AocError, DailyInput, DayPartFn, InputSource, InputType, PartFn};
+use aoc_common::{algo, bit_grid, coord, grid, image, recorder, registry::Year, sparse_grid};
 
 // I wanted to have my modules be named dayXX.rs, but I didn't want them all in ./src.  If I put them into sub dirs for
 // each day, then by convention, I must name them dayXX/mod.rs.  I could use the attribute #[path=...] to supply an alternate
@@ -34,92 +27,22 @@ pub fn get_day_parts() -> Vec<DayPartFn> {
     day_parts
 }
 
-pub type PartFn = fn(DailyInput) -> Result<String, AocError>;
+pub struct Aoc2024;
 
-pub struct DayPartFn {
-    pub day: usize,
-    pub part: usize,
-    pub function: PartFn,
-}
-impl DayPartFn {
-    pub fn new(day: usize, part: usize, function: PartFn) -> Self {
-        Self { day, part, function }
-    }
-}
-
-#[derive(Clone)]
-pub enum InputType {
-    #[allow(dead_code)]
-    Example,
-    Challenge,
-}
-#[derive(Clone)]
-pub struct DailyInput {
-    pub day: usize,
-    pub input_type: InputType,
-    pub number: Option<usize>,
-}
-impl DailyInput {
-    fn get_input_file(&self) -> Result<File, Error> {
-        let day = format!("day{:02}", self.day);
-        let qualifier = match self.input_type {
-            InputType::Example => "example",
-            InputType::Challenge => "challenge",
-        };
-        let file_name = match self.number {
-            Some(number) => format!("src/{day}/.input-{qualifier}-{day}-{number}.txt"),
-            None => format!("src/{day}/.input-{qualifier}-{day}.txt"),
-        };
-        self.open_input_file(&file_name)
-    }
-
-    fn open_input_file(&self, file_name: &str) -> Result<File, Error> {
-        match File::open(file_name) {
-            Ok(f) => Ok(f),
-            Err(e) => Err(Error::new(
-                std::io::ErrorKind::NotFound,
-                format!("Unable to find file {}: {:?}", &file_name, e),
-            )),
-        }
+impl Year for Aoc2024 {
+    fn year(&self) -> usize {
+        2024
     }
 
-    pub fn get_input_lines(&self) -> Result<Vec<String>, Error> {
-        Ok(BufReader::new(self.get_input_file()?).lines().collect::<Result<Vec<String>, _>>().unwrap())
+    fn dir(&self) -> &'static Path {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
     }
 
-    pub fn get_input_as_single_string(&self) -> Result<String, Error> {
-        let mut file = self.get_input_file()?;
-        let mut buffer = String::new();
-        file.read_to_string(&mut buffer)?;
-        Ok(buffer)
+    fn day_parts(&self) -> Vec<DayPartFn> {
+        get_day_parts()
     }
 }
 
-#[derive(Error, Debug)]
-pub enum AocError {
-    #[error("Parse didn't succeed: {message} ")]
-    ParseFailed { message: String },
-
-    #[error("Parse didn't read all input: {remaining}")]
-    ParseNotComplete { remaining: String },
-
-    #[error(transparent)]
-    Log {
-        #[from]
-        source: flexi_logger::FlexiLoggerError,
-    },
-
-    #[error(transparent)]
-    Io {
-        #[from]
-        source: std::io::Error,
-    },
-}
-
-pub fn enable_logging() -> Result<LoggerHandle, AocError> {
-    Logger::try_with_env_or_str("info")?.log_to_stdout().start().map_err(|e| e.into())
-}
-
 pub fn as_i64(captures: &Captures, index: usize) -> i64 {
     captures.get(index).unwrap().as_str().parse::<i64>().unwrap()
 }
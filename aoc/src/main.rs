@@ -0,0 +1,41 @@
+use std::{env, process::ExitCode};
+
+use aoc2022::Aoc2022;
+use aoc2023::Aoc2023;
+use aoc2024::Aoc2024;
+use aoc_common::{enable_logging, registry::Year, runner, AocError};
+
+/// cargo run -- 2024 12,1
+/// cargo run -- 2022 [day[,part]...]  (the latest day when none are given)
+/// cargo run -- 2023 verify [day[,part]...]
+/// cargo run -- all-years [verify | bench] [--jobs N] [--timeout SECS] [--format json|csv|text]
+///
+/// Everything after the year is handled the same way for every year.  See [runner::run] for the rest of the options.
+fn main() -> Result<ExitCode, AocError> {
+    enable_logging()?;
+
+    let years: [&dyn Year; 3] = [&Aoc2022, &Aoc2023, &Aoc2024];
+
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let Some((year_arg, args)) = args.split_first() else {
+        panic!("No arguments.  Expected a year or all-years")
+    };
+    let years = if year_arg == "all-years" {
+        years.to_vec()
+    } else {
+        match years.iter().find(|year| year.year().to_string() == *year_arg) {
+            Some(year) => vec![*year],
+            None => panic!(
+                "Invalid arg {}.  Expected all-years or one of {:?}",
+                year_arg,
+                years.iter().map(|year| year.year()).collect::<Vec<_>>()
+            ),
+        }
+    };
+
+    Ok(if runner::run(&years, args)? {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -1,34 +1,13 @@
+pub mod cycle;
+pub mod linear;
+pub mod search;
+
 use crate::coord::XY;
 use log::debug;
 use std::collections::HashMap;
 use std::fmt::Display;
 
-// find intersection of two lines as specified by
-//    a1 x + b1 y + c1 = 0
-//    a2 x + b2 y + c2 = 0
-pub(crate) fn line_intersection(a1: f64, b1: f64, c1: f64, a2: f64, b2: f64, c2: f64) -> Option<(i64, i64)> {
-    let x: f64 = (b1 * c2 - b2 * c1) / (a1 * b2 - a2 * b1);
-    if x.is_nan() {
-        return None;
-    }
-    if x.trunc() != x {
-        return None;
-    }
-    let x: i64 = num::cast(x)?;
-
-    let y: f64 = (c1 * a2 - c2 * a1) / (a1 * b2 - a2 * b1);
-    if y.is_nan() {
-        return None;
-    }
-    if y.trunc() != y {
-        return None;
-    }
-    let y: i64 = num::cast(y)?;
-    Some((x, y))
-}
-
-#[allow(dead_code)]
-pub(crate) fn gcd(a: u64, b: u64) -> u64 {
+pub fn gcd(a: u64, b: u64) -> u64 {
     if b == 0 {
         a
     } else {
@@ -36,8 +15,7 @@ pub(crate) fn gcd(a: u64, b: u64) -> u64 {
     }
 }
 
-#[allow(dead_code)]
-pub(crate) fn lcm_of_two(a: u64, b: u64) -> u64 {
+pub fn lcm_of_two(a: u64, b: u64) -> u64 {
     if a == 0 || b == 0 {
         0
     } else {
@@ -45,13 +23,11 @@ pub(crate) fn lcm_of_two(a: u64, b: u64) -> u64 {
     }
 }
 
-#[allow(dead_code)]
-pub(crate) fn lcm_of_multiple(numbers: &[u64]) -> u64 {
+pub fn lcm_of_multiple(numbers: &[u64]) -> u64 {
     numbers.iter().cloned().fold(1, lcm_of_two)
 }
 
-#[allow(dead_code)]
-pub(crate) fn count_distinct<T>(values: &[T]) -> HashMap<&T, usize>
+pub fn count_distinct<T>(values: &[T]) -> HashMap<&T, usize>
 where
     T: Eq + PartialEq + std::hash::Hash,
 {
@@ -64,8 +40,7 @@ where
 // https://en.m.wikipedia.org/wiki/Shoelace_formula
 // 1/2 Σ i->n (x[i]* y[i+1] - x[i+1]*y[i])
 // this should work even if XY were changed to use non integers
-#[allow(dead_code)]
-pub(crate) fn shoelace_area(vertices: &[XY]) -> f64 {
+pub fn shoelace_area(vertices: &[XY]) -> f64 {
     let mut sum = 0_f64;
     for i in 0..vertices.len() {
         let (v1, v2) = (
@@ -91,8 +66,7 @@ pub(crate) fn shoelace_area(vertices: &[XY]) -> f64 {
 //
 // i= A+1-b/2
 // This only works with integer vertices
-#[allow(dead_code)]
-pub(crate) fn get_num_interior_points(vertices: &[XY]) -> PicksResult {
+pub fn get_num_interior_points(vertices: &[XY]) -> PicksResult {
     let vertices = &vertices;
     let area = shoelace_area(vertices);
     debug!(" shoelace area={area}");
@@ -136,11 +110,10 @@ pub(crate) fn get_num_interior_points(vertices: &[XY]) -> PicksResult {
 }
 
 #[derive(Debug)]
-#[allow(dead_code)]
-pub(crate) struct PicksResult {
-    shoelace_area: f64,
-    num_boundary_points: usize,
-    num_interior_points: usize,
+pub struct PicksResult {
+    pub shoelace_area: f64,
+    pub num_boundary_points: usize,
+    pub num_interior_points: usize,
 }
 
 impl Display for PicksResult {
@@ -155,28 +128,9 @@ impl Display for PicksResult {
 
 #[cfg(test)]
 mod tests {
-    use crate::algo::{get_num_interior_points, line_intersection, shoelace_area};
+    use crate::algo::{get_num_interior_points, shoelace_area};
     use crate::coord::{RowCol, XY};
 
-    #[test]
-    fn test_line_intersection() {
-        assert_eq!(
-            line_intersection(
-                94.into(),
-                22.into(),
-                (-8400).into(),
-                34.into(),
-                67.into(),
-                (-5400).into()
-            ),
-            Some((80, 40))
-        );
-        assert_eq!(
-            line_intersection(3.into(), 4.into(), 5.into(), 2.into(), 5.into(), 7.into()),
-            None
-        );
-    }
-
     #[test]
     fn row_col_conversions_test() {
         let rc = RowCol(1, 2);
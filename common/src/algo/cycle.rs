@@ -0,0 +1,137 @@
+use num::PrimInt;
+
+/// Steps from `initial` until it gets back to a state it's been in before, and returns `(mu, lambda)`: the number of
+/// steps before the loop starts, and how many steps it takes to go round the loop once.  Uses Brent's algorithm, so
+/// only two states are kept at a time however long it takes to repeat.
+///
+/// Two states are the same when their `key`s are.  The key has to hold everything that decides what happens next, but
+/// can leave out anything that just keeps adding up, like the height of a tower, so the state itself doesn't need to
+/// repeat.  Never returns if the key never repeats.
+pub fn find_cycle<S: Clone, K: Eq>(
+    initial: S,
+    mut step: impl FnMut(S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> (usize, usize) {
+    // The hare runs ahead of where the tortoise is waiting, and the tortoise catches up each time the gap reaches a
+    // power of two, until the hare gets back to it.  The gap's then the length of the loop.
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise_key = key(&initial);
+    let mut hare = step(initial.clone());
+    let mut hare_key = key(&hare);
+    while hare_key != tortoise_key {
+        if power == lambda {
+            tortoise_key = hare_key;
+            power *= 2;
+            lambda = 0;
+        }
+        hare = step(hare);
+        hare_key = key(&hare);
+        lambda += 1;
+    }
+
+    // With the hare a whole loop ahead, they meet when the tortoise gets to the start of the loop.
+    let mut tortoise = initial.clone();
+    let mut hare = (0..lambda).fold(initial, |state, _| step(state));
+    let mut mu = 0;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(tortoise);
+        hare = step(hare);
+        mu += 1;
+    }
+    (mu, lambda)
+}
+
+/// The state after `n` steps, without going round the loop found by [find_cycle] more than once.  Only what's in the
+/// key is sure to be right when the state doesn't repeat as a whole.
+pub fn state_at<S>(initial: S, mut step: impl FnMut(S) -> S, (mu, lambda): (usize, usize), n: usize) -> S {
+    let n = match n < mu {
+        true => n,
+        false => mu + (n - mu) % lambda,
+    };
+    (0..n).fold(initial, |state, _| step(state))
+}
+
+/// The `metric` of the state after `n` steps, for something like a height that goes up or down by the same amount each
+/// time round the loop found by [find_cycle].  Only steps as far as the end of the first time round the loop.
+pub fn metric_at<S, M: PrimInt>(
+    initial: S,
+    mut step: impl FnMut(S) -> S,
+    mut metric: impl FnMut(&S) -> M,
+    (mu, lambda): (usize, usize),
+    n: usize,
+) -> M {
+    if n < mu + lambda {
+        return metric(&(0..n).fold(initial, |state, _| step(state)));
+    }
+
+    let mut state = (0..mu).fold(initial, |state, _| step(state));
+    let mut metrics = vec![metric(&state)];
+    for _ in 0..lambda {
+        state = step(state);
+        metrics.push(metric(&state));
+    }
+
+    // Take the smaller from the bigger so an unsigned metric that goes down each lap doesn't underflow.
+    let laps = M::from((n - mu) / lambda).unwrap();
+    let (start, end) = (metrics[0], metrics[lambda]);
+    match end >= start {
+        true => metrics[(n - mu) % lambda] + laps * (end - start),
+        false => metrics[(n - mu) % lambda] - laps * (start - end),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algo::cycle::{find_cycle, metric_at, state_at};
+
+    /// Counts up to 3 and then goes round 3, 4, 5, 6, 7, adding up everything it's counted along the way.
+    fn step((count, total): (usize, i64)) -> (usize, i64) {
+        let next = match count < 7 {
+            true => count + 1,
+            false => 3,
+        };
+        (next, total + next as i64)
+    }
+
+    fn brute_force(n: usize) -> (usize, i64) {
+        (0..n).fold((0, 0), |state, _| step(state))
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        assert_eq!(find_cycle((0, 0), step, |(count, _)| *count), (3, 5));
+        assert_eq!(find_cycle(5, |n| (n + 1) % 6, |n| *n), (0, 6));
+        assert_eq!(find_cycle(1, |_| 1, |n| *n), (0, 1));
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let cycle = find_cycle((0, 0), step, |(count, _)| *count);
+        for n in [0, 2, 3, 7, 8, 9, 100, 1234] {
+            assert_eq!(state_at((0, 0), step, cycle, n).0, brute_force(n).0, "state at {n}");
+            assert_eq!(
+                metric_at((0, 0), step, |(_, total)| *total, cycle, n),
+                brute_force(n).1,
+                "metric at {n}"
+            );
+        }
+        assert_eq!(
+            metric_at((0, 0), step, |(_, total)| *total, cycle, 1_000_000_000_000),
+            4_999_999_999_990
+        );
+    }
+
+    #[test]
+    fn test_extrapolate_unsigned_going_down() {
+        let cycle = find_cycle((0, 0), step, |(count, _)| *count);
+        let left = |(_, total): &(usize, i64)| 10_000_u64 - *total as u64;
+        for n in [0, 3, 8, 100, 1234] {
+            assert_eq!(
+                metric_at((0, 0), step, left, cycle, n),
+                10_000 - brute_force(n).1 as u64,
+                "metric at {n}"
+            );
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use num::{rational::Ratio, Zero};
+
+/// What solving a system of linear equations came to.  A singular system is one that's either [Solution::Inconsistent]
+/// or [Solution::Infinite].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+    /// Exactly one value for each unknown, in the order of the matrix's columns.
+    Unique(Vec<Ratio<i128>>),
+    /// The equations contradict each other, so nothing satisfies all of them.
+    Inconsistent,
+    /// There are fewer independent equations than unknowns, so a whole line (or more) of values satisfies them.
+    Infinite,
+}
+impl Solution {
+    pub fn is_singular(&self) -> bool {
+        !matches!(self, Self::Unique(_))
+    }
+
+    /// The unique solution, if there is one and every value in it is a whole number.
+    pub fn integers(&self) -> Option<Vec<i128>> {
+        match self {
+            Self::Unique(values) => values.iter().map(|v| v.is_integer().then(|| v.to_integer())).collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Solves
+///
+/// ```text
+///    a[0][0] x + a[0][1] y = b[0]
+///    a[1][0] x + a[1][1] y = b[1]
+/// ```
+///
+/// with Cramer's rule.
+pub fn solve_2x2(a: [[i128; 2]; 2], b: [i128; 2]) -> Solution {
+    let det = a[0][0] * a[1][1] - a[0][1] * a[1][0];
+    if det.is_zero() {
+        // Each equation's a multiple of the other, or one of them's all zeros.  The right hand sides have to be the
+        // same multiple for both to hold at once.
+        return solve(&[a[0].to_vec(), a[1].to_vec()], &b);
+    }
+    let det_x = b[0] * a[1][1] - a[0][1] * b[1];
+    let det_y = a[0][0] * b[1] - b[0] * a[1][0];
+    Solution::Unique(vec![Ratio::new(det_x, det), Ratio::new(det_y, det)])
+}
+
+/// Solves `a x = b` for any number of equations and unknowns, with `a` given row by row.
+///
+/// Uses fraction-free (Bareiss) Gaussian elimination, so every division along the way is exact and everything stays a
+/// whole number until the final back substitution.  Values still need to fit in an `i128` part way through, which
+/// grows with the size of the determinant of `a`.
+pub fn solve(a: &[Vec<i128>], b: &[i128]) -> Solution {
+    assert_eq!(a.len(), b.len(), "one right hand side per equation");
+    let unknowns = a.first().map_or(0, Vec::len);
+    let mut rows: Vec<Vec<i128>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, b)| {
+            assert_eq!(row.len(), unknowns, "every equation has the same number of unknowns");
+            row.iter().copied().chain([*b]).collect()
+        })
+        .collect();
+
+    // Reduce to row echelon form.  Each pass's pivot divides out of the next pass, which keeps every entry a minor of
+    // the original matrix rather than letting them grow exponentially.
+    let mut previous_pivot = 1;
+    let mut pivot_cols = Vec::new();
+    for col in 0..unknowns {
+        let rank = pivot_cols.len();
+        let Some(pivot_row) = (rank..rows.len()).find(|r| !rows[*r][col].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, pivot_row);
+        let (above, below) = rows.split_at_mut(rank + 1);
+        let pivot_row = &above[rank];
+        let pivot = pivot_row[col];
+        for row in below {
+            let factor = row[col];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row).skip(col) {
+                *value = (pivot * *value - factor * pivot_value) / previous_pivot;
+            }
+        }
+        previous_pivot = pivot;
+        pivot_cols.push(col);
+    }
+
+    let rank = pivot_cols.len();
+    if rows[rank..].iter().any(|row| !row[unknowns].is_zero()) {
+        return Solution::Inconsistent;
+    }
+    if rank < unknowns {
+        return Solution::Infinite;
+    }
+
+    let mut values = vec![Ratio::zero(); unknowns];
+    for r in (0..rank).rev() {
+        let rest = (r + 1..unknowns).fold(Ratio::from(rows[r][unknowns]), |sum, c| sum - values[c] * rows[r][c]);
+        values[r] = rest / rows[r][r];
+    }
+    Solution::Unique(values)
+}
+
+/// Returns `(g, x, y)` where `g` is the non-negative greatest common divisor of `a` and `b`, and `a x + b y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while !r.is_zero() {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algo::linear::{extended_gcd, solve, solve_2x2, Solution};
+    use num::rational::Ratio;
+
+    fn unique(values: &[(i128, i128)]) -> Solution {
+        Solution::Unique(values.iter().map(|(n, d)| Ratio::new(*n, *d)).collect())
+    }
+
+    #[test]
+    fn test_solve_2x2() {
+        assert_eq!(
+            solve_2x2([[94, 22], [34, 67]], [8400, 5400]).integers(),
+            Some(vec![80, 40])
+        );
+        assert_eq!(solve_2x2([[3, 4], [2, 5]], [-5, -7]), unique(&[(3, 7), (-11, 7)]));
+        assert_eq!(solve_2x2([[3, 4], [2, 5]], [-5, -7]).integers(), None);
+
+        // Big enough that an f64 can't tell a whole number from one that's a fraction off.
+        let offset = 10_000_000_000_000;
+        assert_eq!(
+            solve_2x2([[26, 67], [66, 21]], [offset + 12748, offset + 12176]).integers(),
+            Some(vec![118679050709, 103199174542])
+        );
+        assert_eq!(
+            solve_2x2([[94, 22], [34, 67]], [offset + 8401, offset + 5400]).integers(),
+            None
+        );
+
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 6]), Solution::Infinite);
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 7]), Solution::Inconsistent);
+        assert_eq!(solve_2x2([[0, 0], [0, 0]], [0, 0]), Solution::Infinite);
+        assert_eq!(solve_2x2([[0, 0], [1, 1]], [1, 2]), Solution::Inconsistent);
+        assert!(solve_2x2([[1, 2], [2, 4]], [3, 6]).is_singular());
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve(&[vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]], &[8, -11, -3]),
+            unique(&[(2, 1), (3, 1), (-1, 1)])
+        );
+        // Needs a row swap to find a pivot for the first column.
+        assert_eq!(
+            solve(&[vec![0, 1, 1], vec![1, 0, 1], vec![1, 1, 0]], &[1, 1, 1]),
+            unique(&[(1, 2), (1, 2), (1, 2)])
+        );
+        // More equations than unknowns, all agreeing.
+        assert_eq!(
+            solve(&[vec![1, 1], vec![1, -1], vec![2, 0]], &[3, 1, 4]),
+            unique(&[(2, 1), (1, 1)])
+        );
+        assert_eq!(
+            solve(&[vec![1, 1], vec![1, -1], vec![2, 0]], &[3, 1, 5]),
+            Solution::Inconsistent
+        );
+        // The third equation is the sum of the first two.
+        assert_eq!(
+            solve(&[vec![1, 2, 3], vec![4, 5, 6], vec![5, 7, 9]], &[1, 2, 3]),
+            Solution::Infinite
+        );
+        assert_eq!(
+            solve(&[vec![1, 2, 3], vec![4, 5, 6], vec![5, 7, 9]], &[1, 2, 4]),
+            Solution::Inconsistent
+        );
+        // Fewer equations than unknowns.
+        assert_eq!(solve(&[vec![1, 1, 1]], &[1]), Solution::Infinite);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (46, 240), (-240, 46), (7, 0), (0, -7), (13, 13), (0, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert!(g >= 0);
+            assert_eq!(a * x + b * y, g, "{a} {b}");
+        }
+        assert_eq!(extended_gcd(240, 46).0, 2);
+        assert_eq!(extended_gcd(0, -7).0, 7);
+    }
+}
@@ -0,0 +1,370 @@
+use num::{BigUint, One};
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// What a search found: the cheapest known cost to each state it reached and every state it got there from at that
+/// cost, so any of the cheapest paths to them can be rebuilt, plus the goals it got to.
+///
+/// The search stops once it's found every goal that's as cheap as the first, so states it had reached but not yet
+/// explored from by then might have cheaper costs or other ways there it didn't find.  Everything cheaper than the
+/// goal is final.  Counting or listing the ways to a goal needs every step to cost something, since a free step could
+/// go round in circles.
+#[derive(Debug, Clone)]
+pub struct Search<S, C> {
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    goals: Vec<S>,
+}
+
+impl<S, C> Default for Search<S, C> {
+    fn default() -> Self {
+        Self {
+            costs: HashMap::new(),
+            predecessors: HashMap::new(),
+            goals: vec![],
+        }
+    }
+}
+
+impl<S: Eq + Hash + Clone, C: Copy + Ord> Search<S, C> {
+    /// The first goal the search got to, if it got to one.
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    /// Every goal that's as cheap to get to as the first.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// The cost of getting to the goal.
+    pub fn cost(&self) -> Option<C> {
+        self.goal().and_then(|goal| self.cost_to(goal))
+    }
+
+    pub fn cost_to(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// The states from a start to the goal, inclusive.  When there's more than one cheapest path, it's the first one
+    /// found.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal().and_then(|goal| self.path_to(goal))
+    }
+
+    /// The states from a start to `state`, inclusive, if the search reached it.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state one step back along the cheapest known ways to `state`, in the order they were found.  Starts don't
+    /// have any.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Every cheapest path from a start to any of the goals.  There can be exponentially many, so check
+    /// [Search::count_optimal_paths] first if that might be a problem.
+    pub fn all_optimal_paths(&self) -> Vec<Vec<S>> {
+        let mut paths = vec![];
+        // paths are built backwards from each goal and flipped once they get back to a start
+        let mut partial_paths = self.goals.iter().map(|goal| vec![goal.clone()]).collect::<Vec<_>>();
+        while let Some(mut path) = partial_paths.pop() {
+            let predecessors = self.predecessors(path.last().unwrap());
+            if predecessors.is_empty() {
+                path.reverse();
+                paths.push(path);
+                continue;
+            }
+            for previous in predecessors {
+                let mut longer = path.clone();
+                longer.push(previous.clone());
+                partial_paths.push(longer);
+            }
+        }
+        paths
+    }
+
+    /// The number of cheapest paths from a start to any of the goals, which can easily outgrow any fixed size integer.
+    pub fn count_optimal_paths(&self) -> BigUint {
+        // every step costs something, so adding up the ways to each state from the cheapest up has always counted the
+        // ways to its predecessors first
+        let mut states = self.nodes_on_any_optimal_path().into_iter().collect::<Vec<_>>();
+        states.sort_by_key(|state| self.costs[state]);
+
+        let mut counts = HashMap::<S, BigUint>::with_capacity(states.len());
+        for state in states {
+            let predecessors = self.predecessors(&state);
+            let count = match predecessors.is_empty() {
+                true => BigUint::one(),
+                false => predecessors.iter().map(|previous| &counts[previous]).sum(),
+            };
+            counts.insert(state, count);
+        }
+        self.goals.iter().map(|goal| &counts[goal]).sum()
+    }
+
+    /// Every state that's on at least one cheapest path from a start to any of the goals.
+    pub fn nodes_on_any_optimal_path(&self) -> HashSet<S> {
+        let mut nodes = HashSet::new();
+        let mut to_visit = self.goals.clone();
+        while let Some(state) = to_visit.pop() {
+            if !nodes.contains(&state) {
+                to_visit.extend(self.predecessors(&state).iter().cloned());
+                nodes.insert(state);
+            }
+        }
+        nodes
+    }
+
+    /// Every state reached and its cost, in no particular order.
+    pub fn reached(&self) -> impl Iterator<Item = (&S, C)> {
+        self.costs.iter().map(|(state, cost)| (state, *cost))
+    }
+}
+
+/// Breadth first search for when every step costs the same, so the cost is the number of steps from the nearest start.
+///
+/// Stops once it's got to every state where `is_goal` is true that's as close as the first, or once everything reachable
+/// has been explored.  Passing `|_| false` gets the distance to everything.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Eq + Hash + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::default();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = search.costs.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back(start);
+        }
+    }
+
+    let mut goal_cost = None;
+    while let Some(state) = queue.pop_front() {
+        let cost = search.costs[&state];
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            search.goals.push(state);
+            continue;
+        }
+        for next in successors(&state) {
+            match search.costs.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(cost + 1);
+                    search.predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push_back(next);
+                }
+                Entry::Occupied(entry) if *entry.get() == cost + 1 => {
+                    search.predecessors.entry(next).or_default().push(state.clone());
+                }
+                Entry::Occupied(_) => {}
+            }
+        }
+    }
+    search
+}
+
+/// Cheapest first search, where `successors` gives each state that can be stepped to along with the cost of the step.
+/// Costs can't be negative.
+///
+/// Stops once it's got to every state where `is_goal` is true that's as cheap as the first, which is always one of the
+/// cheapest to get to, or once everything reachable has been explored.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// [dijkstra], but exploring the states that look closest to a goal first.  `heuristic` estimates the cost from a state
+/// to the nearest goal and must never overestimate it, or the goal found might not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::default();
+    // The heap holds indexes into `queued` rather than the states themselves so states don't need to be `Ord`.  Ties go
+    // to whichever was queued first.
+    let mut queued = vec![];
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = search.costs.entry(start.clone()) {
+            entry.insert(C::default());
+            heap.push(Reverse((heuristic(&start), queued.len())));
+            queued.push(Some((start, C::default())));
+        }
+    }
+
+    let mut goal_cost = None;
+    while let Some(Reverse((estimate, index))) = heap.pop() {
+        if goal_cost.is_some_and(|goal_cost| estimate > goal_cost) {
+            break;
+        }
+        let (state, cost) = queued[index].take().unwrap();
+        if search.costs[&state] < cost {
+            // a cheaper way here was queued after this one and has already been explored
+            continue;
+        }
+        if is_goal(&state) {
+            goal_cost = Some(cost);
+            search.goals.push(state);
+            continue;
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            match search.costs.get(&next) {
+                Some(known) if *known < next_cost => continue,
+                Some(known) if *known == next_cost => {
+                    search.predecessors.entry(next).or_default().push(state.clone());
+                    continue;
+                }
+                _ => {}
+            }
+            search.costs.insert(next.clone(), next_cost);
+            search.predecessors.insert(next.clone(), vec![state.clone()]);
+            heap.push(Reverse((next_cost + heuristic(&next), queued.len())));
+            queued.push(Some((next, next_cost)));
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algo::search::{astar, bfs, dijkstra},
+        coord::{rc, RowCol},
+        grid::Grid,
+    };
+    use std::collections::HashSet;
+
+    fn maze() -> Grid {
+        Grid::parse("S..#\n.#..\n...E").unwrap()
+    }
+
+    fn open_neighbors(grid: &Grid, rc: RowCol) -> Vec<RowCol> {
+        grid.neighbors4(rc).filter(|(_, b)| *b != b'#').map(|(rc, _)| rc).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let end = grid.find(b'E').unwrap();
+        let search = bfs([rc(0, 0)], |rc| open_neighbors(&grid, *rc), |rc| *rc == end);
+        assert_eq!(search.cost(), Some(5));
+        let path = search.path().unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!((path[0], path[5]), (rc(0, 0), end));
+        assert!(path.windows(2).all(|step| step[0].manhattan_distance(&step[1]) == 1));
+
+        // without a goal it explores everything, and the cheapest of several starts wins
+        let everything = bfs([rc(0, 0), rc(2, 3)], |rc| open_neighbors(&grid, *rc), |_| false);
+        assert_eq!(everything.goal(), None);
+        assert_eq!(everything.reached().count(), 10);
+        assert_eq!(everything.cost_to(&rc(1, 3)), Some(1));
+        assert!(everything.predecessors(&rc(0, 0)).is_empty());
+        assert_eq!(everything.path_to(&rc(1, 1)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // the direct way from a to d costs more than going around
+        let edges = |node: &char| match node {
+            'a' => vec![('d', 10), ('b', 1)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+        let search = dijkstra(['a'], edges, |node| *node == 'd');
+        assert_eq!(search.cost(), Some(6));
+        assert_eq!(search.path(), Some(vec!['a', 'b', 'c', 'd']));
+
+        let unreachable = dijkstra(['b'], edges, |node| *node == 'a');
+        assert_eq!(unreachable.cost(), None);
+        assert_eq!(unreachable.path(), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = maze();
+        let end = grid.find(b'E').unwrap();
+        let successors = |rc: &RowCol| open_neighbors(&grid, *rc).into_iter().map(|next| (next, 1));
+        let heuristic = |rc: &RowCol| rc.manhattan_distance(&end);
+        let search = astar([rc(0, 0)], successors, heuristic, |rc| *rc == end);
+        assert_eq!(search.cost(), Some(5));
+        assert_eq!(search.cost(), dijkstra([rc(0, 0)], successors, |rc| *rc == end).cost());
+    }
+
+    #[test]
+    fn test_optimal_paths() {
+        let open = Grid::new_repeating(rc(0, 0), rc(2, 2), b'.');
+        let successors = |rc: &RowCol| open_neighbors(&open, *rc).into_iter().map(|next| (next, 1_u64));
+
+        let corner_to_corner = dijkstra([rc(0, 0)], successors, |at| *at == rc(2, 2));
+        assert_eq!(corner_to_corner.cost(), Some(4));
+        assert_eq!(corner_to_corner.count_optimal_paths(), 6_u32.into());
+        assert_eq!(corner_to_corner.nodes_on_any_optimal_path().len(), 9);
+        assert_eq!(corner_to_corner.predecessors(&rc(1, 1)), &[rc(1, 0), rc(0, 1)]);
+        let paths = corner_to_corner.all_optimal_paths();
+        assert_eq!(paths.len(), 6);
+        assert_eq!(paths.iter().collect::<HashSet<_>>().len(), 6);
+        assert!(paths.iter().all(|path| path.len() == 5 && path[0] == rc(0, 0) && path[4] == rc(2, 2)));
+
+        // both of the other corners are as cheap as each other, the middle of the far side isn't on the way to either
+        let either_corner = bfs(
+            [rc(0, 0)],
+            |rc| open_neighbors(&open, *rc),
+            |at| [rc(0, 2), rc(2, 0)].contains(at),
+        );
+        assert_eq!(either_corner.goals().len(), 2);
+        assert_eq!(either_corner.count_optimal_paths(), 2_u32.into());
+        assert_eq!(
+            either_corner.nodes_on_any_optimal_path(),
+            HashSet::from([rc(0, 0), rc(0, 1), rc(0, 2), rc(1, 0), rc(2, 0)])
+        );
+
+        let bigger = Grid::new_repeating(rc(0, 0), rc(39, 39), b'.');
+        let across = astar(
+            [rc(0, 0)],
+            |rc| open_neighbors(&bigger, *rc).into_iter().map(|next| (next, 1)),
+            |at| at.manhattan_distance(&rc(39, 39)),
+            |at| *at == rc(39, 39),
+        );
+        assert_eq!(across.cost(), Some(78));
+        assert_eq!(across.count_optimal_paths().to_string(), "27217014869199032015600");
+    }
+}
@@ -0,0 +1,133 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use crate::{AocError, InputType};
+
+/// The name of the answers file in each year's crate directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Known correct answers for one year, read from that year's `answers.toml`.  Answers are grouped by day, then part,
+/// then input:
+///
+/// ```toml
+/// [day01.part1]
+/// example1 = "142"
+/// challenge = "54388"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn load(file_name: &Path) -> Result<Self, AocError> {
+        Self::parse(&fs::read_to_string(file_name)?)
+    }
+
+    pub fn parse(toml: &str) -> Result<Self, AocError> {
+        Ok(Self {
+            days: toml::from_str(toml)?,
+        })
+    }
+
+    pub fn expected(&self, day: usize, part: usize, input_type: &InputType, number: Option<usize>) -> Option<&str> {
+        self.days
+            .get(&format!("day{:02}", day))?
+            .get(&format!("part{}", part))?
+            .get(&Self::input_key(input_type, number))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn check(
+        &self,
+        day: usize,
+        part: usize,
+        input_type: &InputType,
+        number: Option<usize>,
+        answer: &str,
+    ) -> Verdict {
+        match self.expected(day, part, input_type, number) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// The days with an answer for at least one input.  Days that are still stubs either have no entry or only empty
+    /// answers.
+    pub fn answered_days(&self) -> impl Iterator<Item = usize> + '_ {
+        self.days
+            .iter()
+            .filter(|(_, parts)| parts.values().flat_map(|inputs| inputs.values()).any(|answer| !answer.is_empty()))
+            .filter_map(|(day, _)| day.strip_prefix("day")?.parse().ok())
+    }
+
+    fn input_key(input_type: &InputType, number: Option<usize>) -> String {
+        let qualifier = match input_type {
+            InputType::Example => "example",
+            InputType::Challenge => "challenge",
+        };
+        match number {
+            Some(number) => format!("{qualifier}{number}"),
+            None => qualifier.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict};
+    use crate::InputType;
+
+    const TOML: &str = r#"
+        [day01.part1]
+        example1 = "142"
+        challenge = "54388"
+
+        [day10.part2]
+        challenge = """
+        ##..
+        .##."""
+    "#;
+
+    #[test]
+    fn test_expected() {
+        let answers = Answers::parse(TOML).unwrap();
+        assert_eq!(answers.expected(1, 1, &InputType::Challenge, None), Some("54388"));
+        assert_eq!(answers.expected(1, 1, &InputType::Example, Some(1)), Some("142"));
+        assert_eq!(answers.expected(1, 1, &InputType::Example, None), None);
+        assert_eq!(answers.expected(1, 2, &InputType::Challenge, None), None);
+        assert_eq!(answers.expected(2, 1, &InputType::Challenge, None), None);
+    }
+
+    #[test]
+    fn test_answered_days() {
+        let answers = Answers::parse(&format!("{TOML}\n[day24.part1]\nchallenge = \"\"\n")).unwrap();
+        assert_eq!(answers.answered_days().collect::<Vec<_>>(), vec![1, 10]);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(TOML).unwrap();
+        assert_eq!(answers.check(1, 1, &InputType::Challenge, None, "54388"), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, 1, &InputType::Challenge, None, "54389"),
+            Verdict::Fail {
+                expected: "54388".to_string()
+            }
+        );
+        assert_eq!(answers.check(3, 1, &InputType::Challenge, None, "1"), Verdict::Unknown);
+        assert_eq!(
+            answers.check(10, 2, &InputType::Challenge, None, "        ##..\n        .##."),
+            Verdict::Pass
+        );
+    }
+}
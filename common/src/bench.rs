@@ -0,0 +1,228 @@
+use std::{collections::BTreeMap, fs, io::ErrorKind, path::Path, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::AocError;
+
+pub const DEFAULT_WARMUP_RUNS: usize = 1;
+pub const DEFAULT_RUNS: usize = 10;
+pub const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// Options for `cargo run -- bench`, pulled out of the command line so what remains can be handled as day/part args.
+///
+/// - `--runs=N` timed runs per part
+/// - `--warmup=N` untimed runs per part before timing
+/// - `--threshold=PCT` how much slower than the baseline median a part may get before it's flagged
+/// - `--save` write the results as the new baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub runs: usize,
+    pub threshold_percent: f64,
+    pub save_baseline: bool,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: DEFAULT_WARMUP_RUNS,
+            runs: DEFAULT_RUNS,
+            threshold_percent: DEFAULT_THRESHOLD_PERCENT,
+            save_baseline: false,
+        }
+    }
+}
+
+impl BenchOptions {
+    /// Returns the options along with the args that weren't bench options.
+    pub fn from_args(args: &[String]) -> (Self, Vec<String>) {
+        let mut options = Self::default();
+        let mut remaining = vec![];
+        for arg in args {
+            if let Some(value) = arg.strip_prefix("--runs=") {
+                options.runs = Self::parse_option(arg, value);
+                if options.runs == 0 {
+                    panic!("Invalid arg {}.  Need at least one run", arg);
+                }
+            } else if let Some(value) = arg.strip_prefix("--warmup=") {
+                options.warmup = Self::parse_option(arg, value);
+            } else if let Some(value) = arg.strip_prefix("--threshold=") {
+                options.threshold_percent = Self::parse_option(arg, value);
+            } else if arg == "--save" {
+                options.save_baseline = true;
+            } else {
+                remaining.push(arg.clone());
+            }
+        }
+        (options, remaining)
+    }
+
+    fn parse_option<T: std::str::FromStr>(arg: &str, value: &str) -> T {
+        match value.parse::<T>() {
+            Ok(v) => v,
+            Err(_) => panic!("Invalid arg {}.  Expected a number after the =", arg),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchStats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            panic!("Cannot compute stats without samples");
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let secs = sorted.iter().map(|d| d.as_secs_f64()).collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / secs.len() as f64;
+
+        Self {
+            runs: sorted.len(),
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Medians from a previous `bench --save`, stored as JSON and keyed like `day01.part1`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    parts: BTreeMap<String, BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BaselineEntry {
+    runs: usize,
+    min_nanos: u128,
+    median_nanos: u128,
+    mean_nanos: u128,
+    stddev_nanos: u128,
+}
+
+impl Baseline {
+    /// A missing file is treated as an empty baseline.
+    pub fn load(file_name: &Path) -> Result<Self, AocError> {
+        match fs::read_to_string(file_name) {
+            Ok(json) => Ok(serde_json::from_str(&json)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, file_name: &Path) -> Result<(), AocError> {
+        if let Some(dir) = file_name.parent() {
+            if !dir.as_os_str().is_empty() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+        fs::write(file_name, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn record(&mut self, day: usize, part: usize, stats: &BenchStats) {
+        self.parts.insert(
+            Self::key(day, part),
+            BaselineEntry {
+                runs: stats.runs,
+                min_nanos: stats.min.as_nanos(),
+                median_nanos: stats.median.as_nanos(),
+                mean_nanos: stats.mean.as_nanos(),
+                stddev_nanos: stats.stddev.as_nanos(),
+            },
+        );
+    }
+
+    pub fn median(&self, day: usize, part: usize) -> Option<Duration> {
+        self.parts
+            .get(&Self::key(day, part))
+            .map(|entry| Duration::from_nanos(entry.median_nanos.try_into().unwrap_or(u64::MAX)))
+    }
+
+    /// Percentage change of the median against the baseline, positive when slower.
+    pub fn change_percent(&self, day: usize, part: usize, stats: &BenchStats) -> Option<f64> {
+        let baseline = self.median(day, part)?.as_secs_f64();
+        if baseline == 0.0 {
+            return None;
+        }
+        Some((stats.median.as_secs_f64() - baseline) / baseline * 100.0)
+    }
+
+    fn key(day: usize, part: usize) -> String {
+        format!("day{:02}.part{}", day, part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Baseline, BenchOptions, BenchStats};
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = BenchStats::from_samples(&millis(&[4, 2, 8, 6]));
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.stddev.as_micros(), 2236);
+
+        let stats = BenchStats::from_samples(&millis(&[3, 1, 2]));
+        assert_eq!(stats.median, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_baseline_change() {
+        let mut baseline = Baseline::default();
+        baseline.record(1, 2, &BenchStats::from_samples(&millis(&[10])));
+
+        let slower = BenchStats::from_samples(&millis(&[12]));
+        let change = baseline.change_percent(1, 2, &slower).unwrap();
+        assert!((change - 20.0).abs() < 1e-9);
+
+        assert_eq!(baseline.change_percent(1, 1, &slower), None);
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        let reloaded: Baseline = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded.median(1, 2), Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn test_options() {
+        let args = ["--runs=5", "3", "--save", "--threshold=2.5", "4,1"].map(String::from);
+        let (options, remaining) = BenchOptions::from_args(&args);
+        assert_eq!(
+            options,
+            BenchOptions {
+                warmup: 1,
+                runs: 5,
+                threshold_percent: 2.5,
+                save_baseline: true
+            }
+        );
+        assert_eq!(remaining, vec!["3".to_string(), "4,1".to_string()]);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{
+    coord::{rc, RowCol},
+    grid::Grid,
+    AocError,
+};
+use std::{
+    fmt::Display,
+    ops::{BitAndAssign, BitOrAssign, BitXorAssign, RangeInclusive},
+};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// One bit per cell, for layers like walls or where something's been, addressed the same as a [Grid] from `min`
+/// through `max`.  Each row is packed into whole `u64` words with the lowest col in the lowest bit, so combining layers
+/// or checking them against each other is a word at a time rather than a cell at a time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    rows: usize,
+    cols: usize,
+    min: RowCol,
+    max: RowCol,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// All bits start unset.
+    pub fn new(min: RowCol, max: RowCol) -> Self {
+        let rows: usize = (max.row() - min.row() + 1).try_into().unwrap();
+        let cols: usize = (max.col() - min.col() + 1).try_into().unwrap();
+        let words_per_row = cols.div_ceil(WORD_BITS);
+
+        BitGrid {
+            rows,
+            cols,
+            min,
+            max,
+            words_per_row,
+            words: vec![0; rows.checked_mul(words_per_row).unwrap()], // panics if too big
+        }
+    }
+
+    pub fn get(&self, rc: RowCol) -> Option<bool> {
+        self.locate(rc).map(|(word, bit)| self.words[word] & bit != 0)
+    }
+
+    /// Panics if `rc` is outside the grid.  See [BitGrid::try_set] for when that's expected.
+    pub fn set(&mut self, rc: RowCol, value: bool) {
+        if let Err(e) = self.try_set(rc, value) {
+            panic!("Set failed: {e}");
+        }
+    }
+
+    pub fn try_set(&mut self, rc: RowCol, value: bool) -> Result<(), AocError> {
+        let (word, bit) = self.checked_locate(rc)?;
+        if value {
+            self.words[word] |= bit;
+        } else {
+            self.words[word] &= !bit;
+        }
+        Ok(())
+    }
+
+    /// Sets the bit at `rc`, returning whether it wasn't already set, the same as inserting into a set.  Panics if
+    /// `rc` is outside the grid.
+    pub fn insert(&mut self, rc: RowCol) -> bool {
+        let (word, bit) = self.checked_locate(rc).unwrap_or_else(|e| panic!("Insert failed: {e}"));
+        let was_set = self.words[word] & bit != 0;
+        self.words[word] |= bit;
+        !was_set
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Whether any cell is set in both grids.
+    pub fn intersects(&self, other: &BitGrid) -> bool {
+        self.assert_same_bounds(other);
+        self.words.iter().zip(&other.words).any(|(a, b)| a & b != 0)
+    }
+
+    /// The locations of set cells, by row then by col.
+    pub fn ones(&self) -> impl Iterator<Item = RowCol> + '_ {
+        self.rows()
+            .flat_map(move |r| self.cols().map(move |c| rc(r, c)))
+            .filter(|rc| self.get(*rc) == Some(true))
+    }
+
+    /// Moves every cell in `row` `by` cols, east when positive and west when negative.  Cells moved past the edge are
+    /// dropped and cells moved in from the other edge are unset.
+    pub fn shift_row(&mut self, row: i64, by: i64) {
+        if !self.rows().contains(&row) {
+            panic!("Row {row} outside of range {}..={}", self.min.row(), self.max.row());
+        }
+        let start = (row - self.min.row()) as usize * self.words_per_row;
+        let words = &mut self.words[start..start + self.words_per_row];
+
+        let word_shift = by.unsigned_abs() as usize / WORD_BITS;
+        let bit_shift = by.unsigned_abs() as usize % WORD_BITS;
+        let source = words.to_vec();
+        let word_at = |i: Option<usize>| i.and_then(|i| source.get(i)).copied().unwrap_or(0);
+        for (i, word) in words.iter_mut().enumerate() {
+            *word = if by >= 0 {
+                let from = i.checked_sub(word_shift);
+                let carry = from.and_then(|from| from.checked_sub(1));
+                (word_at(from) << bit_shift) | word_at(carry).checked_shr((WORD_BITS - bit_shift) as u32).unwrap_or(0)
+            } else {
+                let from = Some(i + word_shift);
+                let carry = Some(i + word_shift + 1);
+                (word_at(from) >> bit_shift) | word_at(carry).checked_shl((WORD_BITS - bit_shift) as u32).unwrap_or(0)
+            };
+        }
+        self.clear_past_last_col(row);
+    }
+
+    /// Moves every row `by` cols.  See [BitGrid::shift_row].
+    pub fn shift_cols(&mut self, by: i64) {
+        for row in self.rows() {
+            self.shift_row(row, by);
+        }
+    }
+
+    /// A [Grid] with `set` and `unset` values in place of each bit.
+    pub fn to_grid<T: Clone>(&self, set: T, unset: T) -> Grid<T> {
+        let mut grid = Grid::new_repeating(self.min, self.max, unset);
+        for rc in self.ones() {
+            grid.set(rc, set.clone());
+        }
+        grid
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.cols
+    }
+
+    pub fn min(&self) -> RowCol {
+        self.min
+    }
+
+    pub fn max(&self) -> RowCol {
+        self.max
+    }
+
+    pub fn rows(&self) -> RangeInclusive<i64> {
+        self.min.row()..=self.max.row()
+    }
+
+    pub fn cols(&self) -> RangeInclusive<i64> {
+        self.min.col()..=self.max.col()
+    }
+
+    fn locate(&self, rc: RowCol) -> Option<(usize, u64)> {
+        if !self.rows().contains(&rc.row()) || !self.cols().contains(&rc.col()) {
+            return None;
+        }
+        let row = (rc.row() - self.min.row()) as usize;
+        let col = (rc.col() - self.min.col()) as usize;
+        Some((row * self.words_per_row + col / WORD_BITS, 1 << (col % WORD_BITS)))
+    }
+
+    fn checked_locate(&self, rc: RowCol) -> Result<(usize, u64), AocError> {
+        self.locate(rc).ok_or(AocError::OutOfRange {
+            rc,
+            min: self.min,
+            max: self.max,
+        })
+    }
+
+    /// Bits in the last word of a row past the last col don't belong to any cell and have to stay unset.
+    fn clear_past_last_col(&mut self, row: i64) {
+        let used_bits = self.cols % WORD_BITS;
+        if used_bits != 0 {
+            let last = (row - self.min.row()) as usize * self.words_per_row + self.words_per_row - 1;
+            self.words[last] &= (1 << used_bits) - 1;
+        }
+    }
+
+    fn assert_same_bounds(&self, other: &BitGrid) {
+        assert!(
+            self.min == other.min && self.max == other.max,
+            "Grids cover different cells: {}-{} and {}-{}",
+            self.min,
+            self.max,
+            other.min,
+            other.max
+        );
+    }
+}
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, other: &BitGrid) {
+        self.assert_same_bounds(other);
+        self.words.iter_mut().zip(&other.words).for_each(|(a, b)| *a &= b);
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, other: &BitGrid) {
+        self.assert_same_bounds(other);
+        self.words.iter_mut().zip(&other.words).for_each(|(a, b)| *a |= b);
+    }
+}
+
+impl BitXorAssign<&BitGrid> for BitGrid {
+    fn bitxor_assign(&mut self, other: &BitGrid) {
+        self.assert_same_bounds(other);
+        self.words.iter_mut().zip(&other.words).for_each(|(a, b)| *a ^= b);
+    }
+}
+
+/// Set cells show as `#` and unset ones as `.`.
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_grid(b'#', b'.').fmt(f)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A [BitGrid] over the same cells, set wherever `predicate` matches.
+    pub fn mask(&self, predicate: impl Fn(&T) -> bool) -> BitGrid {
+        let mut bits = BitGrid::new(self.min(), self.max());
+        for (rc, _) in self.cells_matching(predicate) {
+            bits.set(rc, true);
+        }
+        bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bit_grid::BitGrid,
+        coord::{rc, RowCol},
+        grid::Grid,
+    };
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut bits = BitGrid::new(rc(-1, -1), rc(1, 130));
+        assert_eq!(bits.get(rc(0, 0)), Some(false));
+        assert_eq!(bits.get(rc(2, 0)), None);
+
+        assert!(bits.insert(rc(0, 0)));
+        assert!(!bits.insert(rc(0, 0)));
+        bits.set(rc(1, 130), true);
+        bits.set(rc(-1, 63), true);
+        assert_eq!(bits.count_ones(), 3);
+        assert_eq!(bits.ones().collect::<Vec<_>>(), vec![rc(-1, 63), rc(0, 0), rc(1, 130)]);
+
+        bits.set(rc(0, 0), false);
+        assert_eq!(bits.get(rc(0, 0)), Some(false));
+        assert!(bits.try_set(rc(0, 131), true).is_err());
+    }
+
+    #[test]
+    fn test_mask_and_ops() {
+        let grid = Grid::new(&lines(&["#..#", ".##.", "...."]));
+        let walls = grid.mask(|b| *b == b'#');
+        assert_eq!(walls.count_ones(), 4);
+        assert_eq!(walls.to_grid(b'#', b'.'), grid);
+
+        let mut visited = BitGrid::new(grid.min(), grid.max());
+        visited.set(rc(0, 1), true);
+        visited.set(rc(1, 1), true);
+        assert!(visited.intersects(&walls));
+
+        let mut both = visited.clone();
+        both &= &walls;
+        assert_eq!(both.ones().collect::<Vec<_>>(), vec![rc(1, 1)]);
+
+        let mut either = visited.clone();
+        either |= &walls;
+        assert_eq!(either.count_ones(), 5);
+
+        either ^= &walls;
+        assert_eq!(either.ones().collect::<Vec<_>>(), vec![rc(0, 1)]);
+    }
+
+    #[test]
+    fn test_shift() {
+        let cols = 150;
+        let ones_at = |bits: &BitGrid| bits.ones().map(|rc| rc.col()).collect::<Vec<_>>();
+
+        let mut bits = BitGrid::new(rc(0, 0), rc(0, cols - 1));
+        [0, 62, 63, 64, 149].iter().for_each(|c| bits.set(RowCol::new(0, *c), true));
+
+        let mut east = bits.clone();
+        east.shift_row(0, 1);
+        assert_eq!(ones_at(&east), vec![1, 63, 64, 65]);
+        east.shift_cols(70);
+        assert_eq!(ones_at(&east), vec![71, 133, 134, 135]);
+
+        let mut west = bits.clone();
+        west.shift_row(0, -63);
+        assert_eq!(ones_at(&west), vec![0, 1, 86]);
+        west.shift_cols(-1);
+        assert_eq!(ones_at(&west), vec![0, 85]);
+
+        let mut gone = bits.clone();
+        gone.shift_cols(cols);
+        assert_eq!(gone.count_ones(), 0);
+    }
+}
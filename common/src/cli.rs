@@ -0,0 +1,148 @@
+use std::path::Path;
+
+use crate::{params::Params, AocError, DailyInput, InputSource, InputType};
+
+/// Which input the runners hand to each part, pulled out of the command line so what remains can be handled as
+/// day/part args.  Without any of these, parts get their challenge input.
+///
+/// - `--example` the day's example input
+/// - `--example=N` the day's Nth example input
+/// - `--input path/to/file.txt` any file
+/// - `--input -` stdin
+/// - `--param key=value` a puzzle param, can be repeated
+#[derive(Debug, Clone)]
+pub struct InputOptions {
+    pub input_type: InputType,
+    pub number: Option<usize>,
+    pub source: InputSource,
+    pub params: Params,
+}
+
+impl Default for InputOptions {
+    fn default() -> Self {
+        Self {
+            input_type: InputType::Challenge,
+            number: None,
+            source: InputSource::DayFiles,
+            params: Params::default(),
+        }
+    }
+}
+
+impl InputOptions {
+    /// Returns the options along with the args that weren't input options.
+    pub fn from_args(args: &[String]) -> Result<(Self, Vec<String>), AocError> {
+        let mut options = Self::default();
+        let mut remaining = vec![];
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "--example" {
+                options.input_type = InputType::Example;
+            } else if let Some(value) = arg.strip_prefix("--example=") {
+                options.input_type = InputType::Example;
+                options.number = match value.parse::<usize>() {
+                    Ok(number) => Some(number),
+                    Err(_) => panic!("Invalid arg {}.  Expected an example number after the =", arg),
+                };
+            } else if arg == "--input" {
+                match args.next() {
+                    Some(path) => options.source = InputSource::from_arg(path)?,
+                    None => panic!("Invalid arg {}.  Expected a file name or - for stdin after it", arg),
+                }
+            } else if arg == "--param" {
+                match args.next() {
+                    Some(param) => options.params.insert_arg(param)?,
+                    None => panic!("Invalid arg {}.  Expected key=value after it", arg),
+                }
+            } else {
+                remaining.push(arg.clone());
+            }
+        }
+        Ok((options, remaining))
+    }
+
+    /// Names the input in reports, like `challenge`, `example2`, a file name or `stdin`.
+    pub fn description(&self) -> String {
+        match &self.source {
+            InputSource::DayFiles => {
+                let qualifier = match self.input_type {
+                    InputType::Example => "example",
+                    InputType::Challenge => "challenge",
+                };
+                match self.number {
+                    Some(number) => format!("{qualifier}{number}"),
+                    None => qualifier.to_string(),
+                }
+            }
+            InputSource::Path(path) => path.display().to_string(),
+            InputSource::Bytes(_) => "stdin".to_string(),
+        }
+    }
+
+    /// The input for `day`, with the day's own files found under the year's crate directory `dir`.
+    pub fn daily_input(&self, dir: &Path, day: usize) -> DailyInput {
+        DailyInput {
+            day,
+            input_type: self.input_type.clone(),
+            number: self.number,
+            source: self.source.clone(),
+            params: self.params.clone(),
+            dir: dir.to_path_buf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::InputOptions;
+    use crate::{InputSource, InputType};
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_defaults() {
+        let (options, remaining) = InputOptions::from_args(&args(&["12,1", "3"])).unwrap();
+        assert!(matches!(options.input_type, InputType::Challenge));
+        assert_eq!(options.number, None);
+        assert!(matches!(options.source, InputSource::DayFiles));
+        assert_eq!(options.description(), "challenge");
+        assert_eq!(remaining, args(&["12,1", "3"]));
+    }
+
+    #[test]
+    fn test_example() {
+        let (options, remaining) = InputOptions::from_args(&args(&["12,1", "--example=2"])).unwrap();
+        assert!(matches!(options.input_type, InputType::Example));
+        assert_eq!(options.number, Some(2));
+        assert_eq!(options.description(), "example2");
+        assert_eq!(remaining, args(&["12,1"]));
+
+        let (options, _) = InputOptions::from_args(&args(&["--example", "12,1"])).unwrap();
+        assert!(matches!(options.input_type, InputType::Example));
+        assert_eq!(options.number, None);
+    }
+
+    #[test]
+    fn test_input_path() {
+        let (options, remaining) = InputOptions::from_args(&args(&["12,1", "--input", "other.txt", "4"])).unwrap();
+        assert!(matches!(&options.source, InputSource::Path(path) if path == Path::new("other.txt")));
+        assert_eq!(options.description(), "other.txt");
+        assert_eq!(remaining, args(&["12,1", "4"]));
+    }
+
+    #[test]
+    fn test_params() {
+        let (options, remaining) =
+            InputOptions::from_args(&args(&["11,1", "--param", "blinks=75", "--param", "other=x"])).unwrap();
+        assert_eq!(
+            options.daily_input(Path::new(""), 11).param("blinks", 6, 25).unwrap(),
+            75
+        );
+        assert_eq!(options.params.get::<String>("other").unwrap(), Some("x".to_string()));
+        assert_eq!(remaining, args(&["11,1"]));
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt::Display;
 use strum_macros::EnumIter;
 
-#[derive(Debug, Clone,Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct Offset(pub i64, pub i64);
 
 #[derive(EnumIter, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -15,9 +15,8 @@ pub enum Direction {
     W,
     NW,
 }
-pub const NSEW: [Direction;4] = [Direction::N, Direction::S , Direction::E, Direction::W];
+pub const NSEW: [Direction; 4] = [Direction::N, Direction::S, Direction::E, Direction::W];
 impl Direction {
-    #[allow(dead_code)]
     pub fn turn_cw_90(&self) -> Self {
         match self {
             Direction::N => Direction::E,
@@ -31,7 +30,6 @@ impl Direction {
         }
     }
 
-    #[allow(dead_code)]
     pub fn turn_ccw_90(&self) -> Self {
         match self {
             Direction::N => Direction::W,
@@ -45,7 +43,6 @@ impl Direction {
         }
     }
 
-    #[allow(dead_code)]
     pub fn turn_180(&self) -> Self {
         match self {
             Direction::N => Direction::S,
@@ -59,7 +56,6 @@ impl Direction {
         }
     }
 
-    #[allow(dead_code)]
     pub fn cw_turn_difference(&self, other: Direction) -> u64 {
         let mut d = *self;
         let mut count = 0;
@@ -70,7 +66,6 @@ impl Direction {
         count
     }
 
-    #[allow(dead_code)]
     pub fn ccw_turn_difference(&self, other: Direction) -> u64 {
         let mut d = *self;
         let mut count = 0;
@@ -85,13 +80,13 @@ impl From<Direction> for char {
     fn from(val: Direction) -> Self {
         match val {
             Direction::N => '^',
-            Direction::NE => todo!(),
+            Direction::NE => '/',
             Direction::E => '>',
-            Direction::SE => todo!(),
+            Direction::SE => '\\',
             Direction::S => 'v',
-            Direction::SW => todo!(),
+            Direction::SW => '/',
             Direction::W => '<',
-            Direction::NW => todo!(),
+            Direction::NW => '\\',
         }
     }
 }
@@ -110,7 +105,15 @@ impl Display for Direction {
 }
 #[cfg(test)]
 mod tests {
-    use super::Direction;
+    use super::{rc, Direction};
+
+    #[test]
+    fn test_direction_to() {
+        assert_eq!(rc(2, 2).direction_to(&rc(0, 2)), Some(Direction::N));
+        assert_eq!(rc(2, 2).direction_to(&rc(5, -1)), Some(Direction::SW));
+        assert_eq!(rc(2, 2).direction_to(&rc(3, 9)), Some(Direction::SE));
+        assert_eq!(rc(2, 2).direction_to(&rc(2, 2)), None);
+    }
 
     #[test]
     fn test_count_ccw_turns() {
@@ -132,13 +135,11 @@ mod tests {
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct RowCol(pub i64, pub i64);
 
-#[allow(dead_code)]
-pub(crate) const fn rc(row: i64, col: i64) -> RowCol {
+pub const fn rc(row: i64, col: i64) -> RowCol {
     RowCol(row, col)
 }
 
 ///  2D coordinate: row ↕ , col ↔
-#[allow(dead_code)]
 impl RowCol {
     pub fn new(row: i64, col: i64) -> RowCol {
         RowCol(row, col)
@@ -197,6 +198,25 @@ impl RowCol {
     pub fn diff(&self, other: &Self) -> Offset {
         Offset(self.0 - other.0, self.1 - other.1)
     }
+    /// The number of steps north, south, east or west it takes to get from here to `other`.
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+    /// The direction to head in from here to get towards `other`, which is only straight at it when it's in a straight
+    /// line or on a diagonal from here.  `None` when they're the same place.
+    pub fn direction_to(&self, other: &Self) -> Option<Direction> {
+        match ((other.row() - self.row()).signum(), (other.col() - self.col()).signum()) {
+            (-1, 0) => Some(Direction::N),
+            (-1, 1) => Some(Direction::NE),
+            (0, 1) => Some(Direction::E),
+            (1, 1) => Some(Direction::SE),
+            (1, 0) => Some(Direction::S),
+            (1, -1) => Some(Direction::SW),
+            (0, -1) => Some(Direction::W),
+            (-1, -1) => Some(Direction::NW),
+            _ => None,
+        }
+    }
 }
 impl Display for RowCol {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -218,12 +238,10 @@ impl From<XY> for RowCol {
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct XY(pub i64, pub i64);
 
-#[allow(dead_code)]
-pub(crate) const fn xy(x: i64, y: i64) -> XY {
+pub const fn xy(x: i64, y: i64) -> XY {
     XY(x, y)
 }
 
-#[allow(dead_code)]
 impl XY {
     pub fn new(x: i64, y: i64) -> XY {
         XY(x, y)
@@ -250,4 +268,3 @@ impl Display for XY {
         write!(f, "X={},Y={}", self.x(), self.y())
     }
 }
-
@@ -0,0 +1,968 @@
+use crate::{
+    coord::{rc, Direction, RowCol, NSEW},
+    parse::sections,
+    AocError,
+};
+use flexi_logger::Level;
+use log::{log, log_enabled};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    iter::successors,
+    mem,
+    ops::{Range, RangeInclusive},
+};
+use strum::IntoEnumIterator;
+
+/// Cells laid out in rows and columns, addressed by [RowCol]s from `min` through `max`.
+///
+/// Grids parsed from text are `Grid<u8>`, one byte per character.  Other cell types are useful for per-cell state like
+/// distances, costs or counts, usually derived from a parsed grid using [Grid::map].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T = u8> {
+    rows: usize,
+    cols: usize,
+    min: RowCol,
+    max: RowCol,
+    data: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new_repeating(min: RowCol, max: RowCol, fill: T) -> Self {
+        let rows: usize = (max.row() - min.row() + 1).try_into().unwrap();
+        let cols: usize = (max.col() - min.col() + 1).try_into().unwrap();
+
+        let size = rows.checked_mul(cols).unwrap(); // panics if too big
+        let data = vec![fill; size];
+
+        Grid {
+            rows,
+            cols,
+            min,
+            max,
+            data,
+        }
+    }
+
+    pub fn get(&self, rc: RowCol) -> Option<T> {
+        if !self.is_in_window(rc) {
+            return None;
+        }
+
+        self._get(rc)
+    }
+
+    /// Panics if `rc` is outside the grid.  See [Grid::try_set] for when that's expected.
+    pub fn set(&mut self, rc: RowCol, value: T) {
+        if let Err(e) = self.try_set(rc, value) {
+            panic!("Set failed: {e}");
+        }
+    }
+
+    fn _get(&self, rc: RowCol) -> Option<T> {
+        let zero_based_rc = self.to_zero_based(rc);
+        let index = self.index_of(zero_based_rc);
+        self.data.get(index).cloned()
+    }
+
+    pub fn fill_horizontal(&mut self, row: i64, col_range: Range<i64>, fill_with: T) {
+        let from = col_range.start.min(col_range.end);
+        let through = col_range.start.max(col_range.end);
+
+        for col in from..=through {
+            self.set((row, col).into(), fill_with.clone());
+        }
+    }
+
+    pub fn fill_vertical(&mut self, row_range: Range<i64>, col: i64, fill_with: T) {
+        let from = row_range.start.min(row_range.end);
+        let through = row_range.start.max(row_range.end);
+
+        for row in from..=through {
+            self.set((row, col).into(), fill_with.clone());
+        }
+    }
+
+    pub fn insert_row_after(&mut self, row: i64) {
+        if row < self.min_row() || row > self.max_row() {
+            panic!("Row {row} outside of range {}..={}", self.min_row(), self.max_row());
+        }
+        let copy = self.data[self.row_offset_range(row)].to_vec();
+        let target = self.row_start_offset(row + 1);
+        self.data.splice(target..target, copy);
+        self.rows += 1;
+        self.max = self.max.plus_row();
+        self.debug_assert_consistent();
+    }
+
+    pub fn transpose(&self) -> Self {
+        let new_data = (0..self.cols)
+            .flat_map(|col_index| (0..self.rows).map(move |row_index| row_index * self.cols + col_index))
+            .map(|index| self.data[index].clone())
+            .collect();
+        let transposed = Self {
+            rows: self.cols,
+            cols: self.rows,
+            min: RowCol::new(self.min.col(), self.min.row()),
+            max: RowCol::new(self.max.col(), self.max.row()),
+            data: new_data,
+        };
+        transposed.debug_assert_consistent();
+        transposed
+    }
+
+    /// Turned a quarter turn clockwise, so the left col becomes the top row.  The turned grid starts at the same `min`.
+    pub fn rotate_cw(&self) -> Self {
+        self.rearranged(self.cols, self.rows, |row, col| (self.rows - 1 - col) * self.cols + row)
+    }
+
+    /// Turned a quarter turn counterclockwise, so the right col becomes the top row.  The turned grid starts at the
+    /// same `min`.
+    pub fn rotate_ccw(&self) -> Self {
+        self.rearranged(self.cols, self.rows, |row, col| col * self.cols + (self.cols - 1 - row))
+    }
+
+    /// Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        self.rearranged(self.rows, self.cols, |row, col| row * self.cols + (self.cols - 1 - col))
+    }
+
+    /// Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        self.rearranged(self.rows, self.cols, |row, col| (self.rows - 1 - row) * self.cols + col)
+    }
+
+    /// A copy of the cells from `min` through `max`, still addressed by the same [RowCol]s.  `max` has to be below and
+    /// right of (or level with) `min`.
+    pub fn subgrid(&self, min: RowCol, max: RowCol) -> Result<Self, AocError> {
+        self.checked_index_of(min)?;
+        self.checked_index_of(max)?;
+        if max.row() < min.row() || max.col() < min.col() {
+            return Err(AocError::OutOfRange {
+                rc: max,
+                min: self.min,
+                max: self.max,
+            });
+        }
+        let mut data = vec![];
+        for row in min.row()..=max.row() {
+            let start = self.index_of(self.to_zero_based(rc(row, min.col())));
+            let end = self.index_of(self.to_zero_based(rc(row, max.col())));
+            data.extend_from_slice(&self.data[start..=end]);
+        }
+        let subgrid = Self {
+            rows: (max.row() - min.row() + 1) as usize,
+            cols: (max.col() - min.col() + 1) as usize,
+            min,
+            max,
+            data,
+        };
+        subgrid.debug_assert_consistent();
+        Ok(subgrid)
+    }
+
+    /// Copies all of `other` over this grid with its `min` landing on `at`.  Nothing's copied unless all of `other`
+    /// fits.
+    pub fn paste(&mut self, at: RowCol, other: &Grid<T>) -> Result<(), AocError> {
+        let far_corner = at.offset(other.rows as i64 - 1, other.cols as i64 - 1);
+        self.checked_index_of(at)?;
+        self.checked_index_of(far_corner)?;
+        for (row_index, other_row) in other.data.chunks(other.cols).enumerate() {
+            let start = self.index_of(self.to_zero_based(at.offset_row(row_index as i64)));
+            self.data[start..start + other.cols].clone_from_slice(other_row);
+        }
+        Ok(())
+    }
+
+    pub fn insert_col_after(&mut self, col: i64) {
+        if col < self.min_col() || col > self.max_col() {
+            panic!("Col {col} outside of range {}..={}", self.min_col(), self.max_col());
+        }
+        let copied_col = (col - self.min_col()) as usize;
+        let mut data = Vec::with_capacity(self.rows * (self.cols + 1));
+        for row in self.data.chunks(self.cols) {
+            data.extend_from_slice(&row[..=copied_col]);
+            data.extend_from_slice(&row[copied_col..]);
+        }
+        self.data = data;
+        self.cols += 1;
+        self.max = self.max.plus_col();
+        self.debug_assert_consistent();
+    }
+
+    pub fn remove_row(&mut self, row: i64) {
+        if row < self.min_row() || row > self.max_row() {
+            panic!("Row {row} outside of range {}..={}", self.min_row(), self.max_row());
+        }
+        self.data.drain(self.row_offset_range(row));
+        self.rows -= 1;
+        self.max = self.max.minus_row();
+        self.debug_assert_consistent();
+    }
+
+    pub fn remove_col(&mut self, col: i64) {
+        if col < self.min_col() || col > self.max_col() {
+            panic!("Col {col} outside of range {}..={}", self.min_col(), self.max_col());
+        }
+        let removed_col = (col - self.min_col()) as usize;
+        let cols = self.cols;
+        let mut index = 0;
+        self.data.retain(|_| {
+            index += 1;
+            (index - 1) % cols != removed_col
+        });
+        self.cols -= 1;
+        self.max = self.max.minus_col();
+        self.debug_assert_consistent();
+    }
+
+    /// A `rows` by `cols` grid starting at the same `min`, taking each cell from the data at `source_index(row, col)`.
+    fn rearranged(&self, rows: usize, cols: usize, source_index: impl Fn(usize, usize) -> usize) -> Self {
+        let data = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .map(|(row, col)| self.data[source_index(row, col)].clone())
+            .collect();
+        let rearranged = Self {
+            rows,
+            cols,
+            min: self.min,
+            max: self.min.offset(rows as i64 - 1, cols as i64 - 1),
+            data,
+        };
+        rearranged.debug_assert_consistent();
+        rearranged
+    }
+
+    pub fn get_row(&self, row_number: i64) -> Option<impl DoubleEndedIterator<Item = T> + '_> {
+        if row_number >= self.min_row() && row_number <= self.max_row() {
+            Some(self.data[self.row_offset_range(row_number)].iter().cloned())
+        } else {
+            None
+        }
+    }
+
+    pub fn get_col(&self, col_number: i64) -> Option<impl Iterator<Item = T> + '_> {
+        if col_number >= self.min_col() && col_number <= self.max_col() {
+            let start = (col_number - self.min_col()) as usize;
+            let step = self.cols;
+            let end: usize = start + (self.rows - 1) * step;
+            Some((start..=end).step_by(step).map(move |offset| self.data[offset].clone()))
+        } else {
+            None
+        }
+    }
+
+    /// The cells north, south, east and west of `rc`, in that order, leaving out any beyond the edge.
+    pub fn neighbors4(&self, rc: RowCol) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        NSEW.iter().filter_map(move |direction| self.located(rc.plus(direction)))
+    }
+
+    /// The cells in all eight directions around `rc`, clockwise from north, leaving out any beyond the edge.
+    pub fn neighbors8(&self, rc: RowCol) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        Direction::iter().filter_map(move |direction| self.located(rc.plus(&direction)))
+    }
+
+    /// The cells stepping away from `rc` in `direction`, not including `rc` itself, until the edge of the grid.
+    pub fn ray(&self, rc: RowCol, direction: Direction) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        successors(Some(rc.plus(&direction)), move |next| Some(next.plus(&direction)))
+            .map_while(|next| self.located(next))
+    }
+
+    /// All cells whose values match `predicate`, by row then by col.
+    pub fn cells_matching<'a>(&'a self, predicate: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = (RowCol, T)> + 'a {
+        self.all_cell_locations_by_row_by_col()
+            .zip(self.data.iter())
+            .filter(move |(_, value)| predicate(value))
+            .map(|(rc, value)| (rc, value.clone()))
+    }
+
+    fn located(&self, rc: RowCol) -> Option<(RowCol, T)> {
+        self.get(rc).map(|value| (rc, value))
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid the same shape as this one, with each cell computed from the cell at the same location in this one.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            min: self.min,
+            max: self.max,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// A grid the same shape as this one and `other`, with each cell computed from the cells at the same location in
+    /// both.  Panics if they're not the same shape.
+    pub fn zip<U, V>(&self, other: &Grid<U>, mut f: impl FnMut(&T, &U) -> V) -> Grid<V> {
+        if self.min != other.min || self.max != other.max {
+            panic!(
+                "Can't zip grids covering different ranges {:?}-{:?} and {:?}-{:?}",
+                self.min, self.max, other.min, other.max
+            );
+        }
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            min: self.min,
+            max: self.max,
+            data: self.data.iter().zip(other.data.iter()).map(|(t, u)| f(t, u)).collect(),
+        }
+    }
+
+    pub fn try_set(&mut self, rc: RowCol, value: T) -> Result<(), AocError> {
+        *self.get_mut(rc)? = value;
+        Ok(())
+    }
+
+    pub fn get_mut(&mut self, rc: RowCol) -> Result<&mut T, AocError> {
+        let index = self.checked_index_of(rc)?;
+        Ok(&mut self.data[index])
+    }
+
+    /// Puts `value` at `rc`, returning what was there.
+    pub fn replace(&mut self, rc: RowCol, value: T) -> Result<T, AocError> {
+        Ok(mem::replace(self.get_mut(rc)?, value))
+    }
+
+    pub fn swap(&mut self, a: RowCol, b: RowCol) -> Result<(), AocError> {
+        let a = self.checked_index_of(a)?;
+        let b = self.checked_index_of(b)?;
+        self.data.swap(a, b);
+        Ok(())
+    }
+
+    fn is_in_window(&self, rc: RowCol) -> bool {
+        rc.row() >= self.min.row()
+            && rc.row() <= self.max.row()
+            && rc.col() >= self.min.col()
+            && rc.col() <= self.max.col()
+    }
+
+    fn checked_index_of(&self, rc: RowCol) -> Result<usize, AocError> {
+        if !self.is_in_window(rc) {
+            return Err(AocError::OutOfRange {
+                rc,
+                min: self.min,
+                max: self.max,
+            });
+        }
+        Ok(self.index_of(self.to_zero_based(rc)))
+    }
+
+    fn debug_assert_consistent(&self) {
+        debug_assert_eq!(
+            self.rows * self.cols,
+            self.data.len(),
+            "{} rows * {} cols doesn't match the {} cells of data",
+            self.rows,
+            self.cols,
+            self.data.len()
+        );
+    }
+
+    fn to_zero_based(&self, rc: RowCol) -> RowCol {
+        let result = RowCol::new(self.to_zero_based_row(rc.row()), self.to_zero_based_col(rc.col()));
+        if result.row() < 0 || result.col() < 0 {
+            panic!(
+                "Conversion of {:?} to zero-based {:?} failed.  Somethings negative when it shouldn't be.  min={:?} max={:?}",
+                rc, result, self.min, self.max
+            );
+        }
+        result
+    }
+
+    fn to_zero_based_row(&self, row: i64) -> i64 {
+        row - self.min.row()
+    }
+
+    fn to_zero_based_col(&self, col: i64) -> i64 {
+        col - self.min.col()
+    }
+
+    fn index_of(&self, zero_based_rc: RowCol) -> usize {
+        (zero_based_rc.row() * self.cols as i64 + zero_based_rc.col()).try_into().unwrap()
+    }
+
+    fn row_col_for_index(&self, data_index: usize) -> RowCol {
+        let col = data_index % self.cols;
+        let row = (data_index - col) / self.cols;
+        self.min.offset(row as i64, col as i64)
+    }
+
+    /// The same grid, addressed from `min` instead, for puzzles that number rows and cols from somewhere other than 0.
+    pub fn with_min(mut self, min: RowCol) -> Self {
+        self.max = min.offset(self.rows as i64 - 1, self.cols as i64 - 1);
+        self.min = min;
+        self
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows
+    }
+
+    pub fn col_count(&self) -> usize {
+        self.cols
+    }
+
+    pub fn min(&self) -> RowCol {
+        self.min
+    }
+
+    pub fn min_row(&self) -> i64 {
+        self.min.row()
+    }
+
+    pub fn min_col(&self) -> i64 {
+        self.min.col()
+    }
+
+    pub fn max(&self) -> RowCol {
+        self.max
+    }
+
+    pub fn max_row(&self) -> i64 {
+        self.max.row()
+    }
+
+    pub fn max_col(&self) -> i64 {
+        self.max.col()
+    }
+
+    pub fn rows(&self) -> RangeInclusive<i64> {
+        self.min_row()..=self.max_row()
+    }
+
+    pub fn cols(&self) -> RangeInclusive<i64> {
+        self.min_col()..=self.max_col()
+    }
+
+    fn row_start_offset(&self, row_number: i64) -> usize {
+        (row_number - self.min_row()) as usize * self.col_count()
+    }
+
+    fn row_end_offset(&self, row_number: i64) -> usize {
+        self.row_start_offset(row_number) + self.col_count() - 1
+    }
+
+    fn row_offset_range(&self, row_number: i64) -> RangeInclusive<usize> {
+        self.row_start_offset(row_number)..=self.row_end_offset(row_number)
+    }
+
+    pub fn row_cell_locations_left_right(&self, row: i64) -> impl Iterator<Item = RowCol> {
+        self.cols().map(move |c| rc(row, c))
+    }
+
+    pub fn col_cell_locations_top_down(&self, col: i64) -> impl Iterator<Item = RowCol> {
+        self.rows().map(move |r| rc(r, col))
+    }
+
+    pub fn all_cell_locations_by_row_by_col(&self) -> impl Iterator<Item = RowCol> + '_ {
+        self.rows().flat_map(|r| self.cols().map(move |c| rc(r, c)))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    pub fn find(&self, value: T) -> Option<RowCol> {
+        self.data.iter().enumerate().find(|(_, v)| **v == value).map(|(i, _)| self.row_col_for_index(i))
+    }
+
+    /// The number of cols where rows `a` and `b` hold different values.  Panics if either row is outside the grid.
+    pub fn row_differences(&self, a: i64, b: i64) -> usize {
+        for row in [a, b] {
+            if row < self.min_row() || row > self.max_row() {
+                panic!("Row {row} outside of range {}..={}", self.min_row(), self.max_row());
+            }
+        }
+        let a = &self.data[self.row_offset_range(a)];
+        let b = &self.data[self.row_offset_range(b)];
+        a.iter().zip(b).filter(|(a, b)| a != b).count()
+    }
+
+    /// The number of rows where cols `a` and `b` hold different values.  Panics if either col is outside the grid.
+    pub fn col_differences(&self, a: i64, b: i64) -> usize {
+        for col in [a, b] {
+            if col < self.min_col() || col > self.max_col() {
+                panic!("Col {col} outside of range {}..={}", self.min_col(), self.max_col());
+            }
+        }
+        let (a, b) = ((a - self.min_col()) as usize, (b - self.min_col()) as usize);
+        self.data.chunks(self.cols).filter(|row| row[a] != row[b]).count()
+    }
+}
+
+impl Grid {
+    /**
+     *  Create new instance from Vec of rows, assuming all row data is ascii.
+     * If rows have differing lengths, short rows are padded with 0s.  [Grid::parse_filled] takes the padding explicitly.
+     */
+    pub fn new(lines: &Vec<String>) -> Self {
+        Self::new_offset(RowCol::new(0, 0), lines)
+    }
+
+    /**
+     *  Create new instance from Vec of rows, assuming all row data is ascii.
+     * If rows have differing lengths, short rows are padded with 0s.  [Grid::parse_filled] takes the padding explicitly.
+     */
+    pub fn new_offset(min: RowCol, lines: &Vec<String>) -> Self {
+        let rows = lines.len();
+        if rows == 0 {
+            panic!("Cannot support zero rows")
+        }
+        let max_cols: usize = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+        if max_cols == 0 {
+            panic!("Cannot support zero cols")
+        }
+        let cols = max_cols;
+        let size = rows.checked_mul(cols).unwrap();
+        let mut data = Vec::with_capacity(size);
+
+        for line in lines {
+            let row_data = line.as_bytes();
+            data.extend_from_slice(row_data);
+            data.resize(data.len() + cols - row_data.len(), 0u8);
+        }
+
+        Grid {
+            rows,
+            cols,
+            min,
+            max: RowCol::new(min.row() + rows as i64 - 1, min.col() + cols as i64 - 1),
+            data,
+        }
+    }
+
+    /// A row per line of `text`, starting at `0, 0`.  Every row has to be the same length; see [Grid::parse_filled] for
+    /// when they aren't.  Blank lines at the end are ignored.
+    pub fn parse(text: &str) -> Result<Self, AocError> {
+        Self::parse_rows(text, None)
+    }
+
+    /// A row per line of `text`, with rows shorter than the longest padded out with `fill`, like maps whose trailing
+    /// spaces have been trimmed.
+    pub fn parse_filled(text: &str, fill: u8) -> Result<Self, AocError> {
+        Self::parse_rows(text, Some(fill))
+    }
+
+    /// A grid per blank-line-separated section of `text`.
+    pub fn parse_many(text: &str) -> Result<Vec<Self>, AocError> {
+        sections(text).iter().map(|section| Self::parse(section)).collect()
+    }
+
+    fn parse_rows(text: &str, fill: Option<u8>) -> Result<Self, AocError> {
+        let mut lines = text.lines().collect::<Vec<_>>();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        let rows = lines.len();
+        let cols = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        if cols == 0 {
+            return Err(AocError::ParseFailed {
+                message: "There are no cells to make a grid from".to_string(),
+            });
+        }
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for (row, line) in lines.iter().enumerate() {
+            if fill.is_none() && line.len() != cols {
+                return Err(AocError::ParseFailed {
+                    message: format!("Row {row} has {} cols but others have {cols}", line.len()),
+                });
+            }
+            data.extend_from_slice(line.as_bytes());
+            data.resize((row + 1) * cols, fill.unwrap_or_default());
+        }
+
+        Ok(Grid {
+            rows,
+            cols,
+            min: rc(0, 0),
+            max: rc(rows as i64 - 1, cols as i64 - 1),
+            data,
+        })
+    }
+
+    /**
+     * Search for all occurrences of any of the chars, returning them all in a HashMap with values containing all of their [RowCol].
+     * Each character's vector will be ordered top-down, left-to-right.
+     */
+    pub fn index(&self, chars: HashSet<u8>) -> HashMap<u8, Vec<RowCol>> {
+        let mut result: HashMap<u8, Vec<RowCol>> = HashMap::new();
+
+        for index in 0..self.data.len() {
+            let b = self.data[index];
+            if chars.contains(&b) {
+                let rc = self.row_col_for_index(index);
+                if let Some(vec) = result.get_mut(&b) {
+                    vec.push(rc);
+                } else {
+                    result.insert(b, Vec::from([rc]));
+                }
+            }
+        }
+
+        result
+    }
+    pub fn index_all(&self) -> HashMap<u8, Vec<RowCol>> {
+        self.index(self.data.iter().cloned().collect())
+    }
+
+    pub fn log_moves_over_self(&self, level: Level, path: &[RowCol]) {
+        if log_enabled!(level) {
+            let mut log_grid = self.clone();
+            log_grid.log_moves(level, path);
+        }
+    }
+
+    pub fn log_moves_over_dots(&self, level: Level, path: &[RowCol]) {
+        if log_enabled!(level) {
+            let mut log_grid = Grid::new_repeating(self.min(), self.max(), b'.');
+            log_grid.log_moves(level, path);
+        }
+    }
+
+    fn log_moves(&mut self, level: Level, path: &[RowCol]) {
+        if log_enabled!(level) {
+            for step in path.windows(2) {
+                if let Some(direction) = step[0].direction_to(&step[1]) {
+                    self.set(step[0], direction.into());
+                }
+            }
+
+            log!(level, "Moves: \n{}", &self);
+            log!(level, "Path Length={}", path.len() - 1);
+        }
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "[{}, {}]-[{}, {}]:",
+            self.min.row(),
+            self.min.col(),
+            self.max.row(),
+            self.max.col()
+        )?;
+        for row in 0..self.rows {
+            let start = row * self.cols;
+            writeln!(
+                f,
+                "{}",
+                self.data[start..(start + self.cols)].iter().map(|n| *n as char).collect::<String>()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        coord::{rc, Direction},
+        grid::Grid,
+        AocError,
+    };
+    use log::debug;
+    use std::collections::HashSet;
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_raw() {
+        let grid = Grid {
+            min: (0, 0).into(),
+            max: (1, 2).into(),
+            rows: 2,
+            cols: 3,
+            data: vec![b'a', b'b', b'c', b'd', b'e', b'f'],
+        };
+
+        assert_eq!(grid.get((0, 0).into()), Some(b'a'));
+        assert_eq!(grid.get((0, 1).into()), Some(b'b'));
+        assert_eq!(grid.get((0, 2).into()), Some(b'c'));
+        assert_eq!(grid.get((1, 0).into()), Some(b'd'));
+        assert_eq!(grid.get((1, 1).into()), Some(b'e'));
+        assert_eq!(grid.get((1, 2).into()), Some(b'f'));
+
+        assert_eq!(grid.get((0, 3).into()), None);
+        assert_eq!(grid.get((1, 3).into()), None);
+        assert_eq!(grid.get((2, 0).into()), None);
+    }
+
+    #[test]
+    fn test_new() {
+        let grid = Grid::new(&vec!["ABC".to_string(), "DEF".to_string()]);
+        debug!("grid={}", grid);
+
+        assert_eq!(grid.get((0, 0).into()), Some(b'A'));
+        assert_eq!(grid.get((0, 1).into()), Some(b'B'));
+        assert_eq!(grid.get((0, 2).into()), Some(b'C'));
+        assert_eq!(grid.get((1, 0).into()), Some(b'D'));
+        assert_eq!(grid.get((1, 1).into()), Some(b'E'));
+        assert_eq!(grid.get((1, 2).into()), Some(b'F'));
+
+        assert_eq!(grid.get((0, 3).into()), None);
+        assert_eq!(grid.get((1, 3).into()), None);
+        assert_eq!(grid.get((2, 0).into()), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("ab\r\ncd\n\n").unwrap();
+        assert_eq!(grid, Grid::new(&lines(&["ab", "cd"])));
+        assert!(Grid::parse("ab\nc").is_err());
+        assert!(Grid::parse("\n\n").is_err());
+
+        let ragged = Grid::parse_filled("  #\n#\n", b' ').unwrap();
+        assert_eq!(ragged.to_string(), "[0, 0]-[1, 2]:\n  #\n#  \n");
+        assert_eq!(ragged.with_min(rc(1, 1)).max(), rc(2, 3));
+
+        let many = Grid::parse_many("ab\ncd\n\n#.\n\n\n.\n").unwrap();
+        assert_eq!(many.len(), 3);
+        assert_eq!(many[2].max(), rc(0, 0));
+    }
+
+    #[test]
+    fn test_with_min() {
+        let grid = Grid::new(&lines(&["..#", "#.."])).with_min(rc(1, 1));
+        assert_eq!(grid.find(b'#'), Some(rc(1, 3)));
+        assert_eq!(grid.get(rc(1, 3)), Some(b'#'));
+        assert_eq!(grid.index(HashSet::from([b'#']))[&b'#'], vec![rc(1, 3), rc(2, 1)]);
+    }
+
+    #[test]
+    fn test_new_with() {
+        let grid = Grid::new_repeating((0, 0).into(), (2, 1).into(), b'.');
+        debug!("grid: {:?}", grid);
+
+        assert_eq!(grid.get((0, 0).into()), Some(b'.'));
+        assert_eq!(grid.get((0, 1).into()), Some(b'.'));
+        assert_eq!(grid.get((0, 2).into()), None);
+        assert_eq!(grid.get((1, 0).into()), Some(b'.'));
+        assert_eq!(grid.get((1, 1).into()), Some(b'.'));
+        assert_eq!(grid.get((1, 2).into()), None);
+        assert_eq!(grid.get((2, 0).into()), Some(b'.'));
+        assert_eq!(grid.get((2, 1).into()), Some(b'.'));
+        assert_eq!(grid.get((2, 2).into()), None);
+    }
+
+    #[test]
+    fn test_set() {
+        let mut grid = Grid::new_repeating((0, 0).into(), (9, 9).into(), b'.');
+        grid.set((0, 0).into(), 100);
+        grid.set((0, 1).into(), 101);
+        grid.set((0, 9).into(), b'^');
+
+        grid.set((1, 0).into(), 102);
+        grid.set((1, 1).into(), 103);
+        grid.set((1, 9).into(), b'^');
+
+        grid.set((9, 0).into(), 100);
+        debug!("{}", grid);
+    }
+
+    #[test]
+    fn test_grid() {
+        let mut wg = Grid::new_repeating((5, 10).into(), (20, 60).into(), b'X');
+        assert_eq!(wg.get((5, 10).into()), Some(b'X'));
+
+        wg.set((6, 11).into(), b'Y');
+
+        assert_eq!(wg.get((6, 11).into()), Some(b'Y'));
+
+        wg.set((5, 10).into(), b'1');
+        wg.set((5, 60).into(), b'2');
+        wg.set((20, 60).into(), b'3');
+        wg.set((20, 10).into(), b'4');
+
+        debug!("{}", wg);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let lines = "abcdef\n\
+        ghijkl\n\
+        mnopqr";
+
+        let expected_transposed = "agm\n\
+            bhn\n\
+            cio\n\
+            djp\n\
+            ekq\n\
+            flr";
+
+        let grid = Grid::new(&lines.split('\n').map(|s| s.trim().to_string()).collect());
+        let expected_grid = Grid::new(&expected_transposed.split('\n').map(|s| s.trim().to_string()).collect());
+
+        assert_eq!(grid.transpose(), expected_grid);
+    }
+
+    #[test]
+    fn test_map_and_zip() {
+        let grid = Grid::new(&vec!["#.#".to_string(), "..#".to_string()]);
+
+        let walls = grid.map(|b| *b == b'#');
+        assert_eq!(walls.get((0, 0).into()), Some(true));
+        assert_eq!(walls.get((1, 0).into()), Some(false));
+        assert_eq!(walls.get_row(1).unwrap().collect::<Vec<_>>(), vec![false, false, true]);
+
+        let mut costs = Grid::new_repeating(grid.min(), grid.max(), u64::MAX);
+        costs.set((1, 1).into(), 7);
+        let open_costs = walls.zip(&costs, |wall, cost| if *wall { None } else { Some(*cost) });
+        assert_eq!(open_costs.get((0, 0).into()), Some(None));
+        assert_eq!(open_costs.get((1, 1).into()), Some(Some(7)));
+        assert_eq!(
+            open_costs.transpose().get_col(1).unwrap().collect::<Vec<_>>(),
+            vec![Some(u64::MAX), Some(7), None]
+        );
+    }
+
+    #[test]
+    fn test_insert_row_after() {
+        let mut grid = Grid::new(&vec!["ab".to_string(), "cd".to_string(), "ef".to_string()]);
+        grid.insert_row_after(1);
+        assert_eq!(grid, Grid::new(&["ab", "cd", "cd", "ef"].map(String::from).to_vec()));
+    }
+
+    #[test]
+    fn test_rotate_and_flip() {
+        let grid = Grid::new_offset(rc(1, 1), &lines(&["abc", "def"]));
+        assert_eq!(
+            grid.rotate_cw(),
+            Grid::new_offset(rc(1, 1), &lines(&["da", "eb", "fc"]))
+        );
+        assert_eq!(
+            grid.rotate_ccw(),
+            Grid::new_offset(rc(1, 1), &lines(&["cf", "be", "ad"]))
+        );
+        assert_eq!(
+            grid.flip_horizontal(),
+            Grid::new_offset(rc(1, 1), &lines(&["cba", "fed"]))
+        );
+        assert_eq!(
+            grid.flip_vertical(),
+            Grid::new_offset(rc(1, 1), &lines(&["def", "abc"]))
+        );
+        assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_horizontal().flip_vertical());
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn test_subgrid_and_paste() {
+        let mut grid = Grid::new(&lines(&["abcd", "efgh", "ijkl"]));
+        let subgrid = grid.subgrid(rc(1, 1), rc(2, 2)).unwrap();
+        assert_eq!(subgrid, Grid::new_offset(rc(1, 1), &lines(&["fg", "jk"])));
+        assert!(grid.subgrid(rc(1, 1), rc(3, 2)).is_err());
+        assert_eq!(
+            grid.subgrid(rc(1, 2), rc(1, 2)).unwrap(),
+            Grid::new_offset(rc(1, 2), &lines(&["g"]))
+        );
+        assert!(matches!(
+            grid.subgrid(rc(1, 2), rc(1, 1)),
+            Err(AocError::OutOfRange { rc: at, min, max }) if at == rc(1, 1) && min == rc(0, 0) && max == rc(2, 3)
+        ));
+        assert!(matches!(
+            grid.subgrid(rc(2, 1), rc(1, 1)),
+            Err(AocError::OutOfRange { .. })
+        ));
+
+        grid.paste(rc(0, 2), &subgrid).unwrap();
+        assert_eq!(grid, Grid::new(&lines(&["abfg", "efjk", "ijkl"])));
+        assert!(grid.paste(rc(2, 3), &subgrid).is_err());
+        assert_eq!(grid, Grid::new(&lines(&["abfg", "efjk", "ijkl"])));
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut grid = Grid::new(&lines(&["abc", "def"]));
+        grid.insert_col_after(1);
+        assert_eq!(grid, Grid::new(&lines(&["abbc", "deef"])));
+        grid.remove_col(0);
+        assert_eq!(grid, Grid::new_offset(rc(0, 0), &lines(&["bbc", "eef"])));
+        grid.remove_row(0);
+        assert_eq!(grid, Grid::new(&lines(&["eef"])));
+        assert_eq!(grid.max(), rc(0, 2));
+    }
+
+    #[test]
+    fn test_differences() {
+        let grid = Grid::new(&lines(&["#.#", "#..", "#.#"]));
+        assert_eq!(grid.row_differences(0, 2), 0);
+        assert_eq!(grid.row_differences(0, 1), 1);
+        assert_eq!(grid.col_differences(0, 2), 1);
+        assert_eq!(grid.col_differences(1, 2), 2);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let mut grid = Grid::new(&vec!["ab".to_string(), "cd".to_string()]);
+
+        // past the last col used to wrap around into the next row
+        assert!(matches!(
+            grid.try_set((0, 2).into(), b'x'),
+            Err(AocError::OutOfRange { rc, min, max }) if rc == (0, 2).into() && min == (0, 0).into() && max == (1, 1).into()
+        ));
+        assert!(grid.get_mut((-1, 0).into()).is_err());
+        assert!(grid.swap((0, 0).into(), (2, 0).into()).is_err());
+        assert_eq!(grid, Grid::new(&vec!["ab".to_string(), "cd".to_string()]));
+
+        *grid.get_mut((1, 1).into()).unwrap() = b'x';
+        assert_eq!(grid.replace((0, 0).into(), b'y').unwrap(), b'a');
+        grid.swap((0, 1).into(), (1, 0).into()).unwrap();
+        assert_eq!(grid, Grid::new(&vec!["yc".to_string(), "bx".to_string()]));
+    }
+
+    #[test]
+    #[should_panic(expected = "R2C0 is outside of R0C0-R1C1")]
+    fn test_set_out_of_range() {
+        Grid::new(&vec!["ab".to_string(), "cd".to_string()]).set((2, 0).into(), b'x');
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(&["abc", "def", "ghi"].map(String::from).to_vec());
+
+        assert_eq!(
+            grid.neighbors4((0, 0).into()).collect::<Vec<_>>(),
+            vec![((1, 0).into(), b'd'), ((0, 1).into(), b'b')]
+        );
+        assert_eq!(
+            grid.neighbors8((1, 1).into()).map(|(_, b)| b).collect::<Vec<_>>(),
+            b"bcfihgda".to_vec()
+        );
+        assert_eq!(
+            grid.neighbors8((2, 2).into()).map(|(_, b)| b).collect::<Vec<_>>(),
+            b"fhe".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::new(&["abc", "def", "ghi"].map(String::from).to_vec());
+
+        assert_eq!(
+            grid.ray((0, 0).into(), Direction::SE).collect::<Vec<_>>(),
+            vec![((1, 1).into(), b'e'), ((2, 2).into(), b'i')]
+        );
+        assert_eq!(
+            grid.ray((1, 2).into(), Direction::W).map(|(_, b)| b).collect::<Vec<_>>(),
+            b"ed".to_vec()
+        );
+        assert_eq!(grid.ray((0, 1).into(), Direction::N).count(), 0);
+    }
+
+    #[test]
+    fn test_cells_matching() {
+        let grid = Grid::new_offset((10, 20).into(), &["#.#", "..#"].map(String::from).to_vec());
+
+        assert_eq!(
+            grid.cells_matching(|b| *b == b'#').map(|(rc, _)| rc).collect::<Vec<_>>(),
+            vec![(10, 20).into(), (10, 22).into(), (11, 22).into()]
+        );
+    }
+}
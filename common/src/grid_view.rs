@@ -0,0 +1,182 @@
+use crate::{
+    coord::{Direction, RowCol, NSEW},
+    grid::Grid,
+};
+use std::iter::successors;
+use strum::IntoEnumIterator;
+
+/// A [Grid] whose edges join up, so stepping off one side comes back on the other.  Every location is wrapped back
+/// onto the grid, so that's where all the [RowCol]s it hands out are.
+#[derive(Debug, Clone, Copy)]
+pub struct WrappingGrid<'a, T = u8> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T: Clone> WrappingGrid<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self { grid }
+    }
+
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// The location on the grid that `rc` wraps around to.
+    pub fn wrap(&self, rc: RowCol) -> RowCol {
+        tile_and_location(self.grid, rc).1
+    }
+
+    pub fn get(&self, rc: RowCol) -> T {
+        self.grid.get(self.wrap(rc)).unwrap()
+    }
+
+    /// The cells north, south, east and west of `rc`, in that order.
+    pub fn neighbors4(&self, rc: RowCol) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        NSEW.iter().map(move |direction| self.located(rc.plus(direction)))
+    }
+
+    /// The cells in all eight directions around `rc`, clockwise from north.
+    pub fn neighbors8(&self, rc: RowCol) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        Direction::iter().map(move |direction| self.located(rc.plus(&direction)))
+    }
+
+    /// The cells stepping away from `rc` in `direction`, not including `rc` itself.  There's no edge to stop at, so
+    /// this never ends.
+    pub fn ray(&self, rc: RowCol, direction: Direction) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        successors(Some(rc.plus(&direction)), move |next| Some(next.plus(&direction))).map(|next| self.located(next))
+    }
+
+    fn located(&self, rc: RowCol) -> (RowCol, T) {
+        let rc = self.wrap(rc);
+        (rc, self.grid.get(rc).unwrap())
+    }
+}
+
+/// A [Grid] repeated endlessly in every direction.  Unlike a [WrappingGrid], locations aren't folded back onto the
+/// grid, so the same cell in different tiles are different locations.  Tiles are numbered by how many grids away from
+/// the original they are, which is tile `R0C0`.
+#[derive(Debug, Clone, Copy)]
+pub struct TiledGrid<'a, T = u8> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T: Clone> TiledGrid<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self { grid }
+    }
+
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// Which tile `rc` falls in.
+    pub fn tile(&self, rc: RowCol) -> RowCol {
+        tile_and_location(self.grid, rc).0
+    }
+
+    /// The location in the original grid that `rc` is a copy of.
+    pub fn location_in_tile(&self, rc: RowCol) -> RowCol {
+        tile_and_location(self.grid, rc).1
+    }
+
+    pub fn get(&self, rc: RowCol) -> T {
+        self.grid.get(self.location_in_tile(rc)).unwrap()
+    }
+
+    /// The cells north, south, east and west of `rc`, in that order.
+    pub fn neighbors4(&self, rc: RowCol) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        NSEW.iter().map(move |direction| self.located(rc.plus(direction)))
+    }
+
+    /// The cells in all eight directions around `rc`, clockwise from north.
+    pub fn neighbors8(&self, rc: RowCol) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        Direction::iter().map(move |direction| self.located(rc.plus(&direction)))
+    }
+
+    /// The cells stepping away from `rc` in `direction`, not including `rc` itself.  This never ends.
+    pub fn ray(&self, rc: RowCol, direction: Direction) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        successors(Some(rc.plus(&direction)), move |next| Some(next.plus(&direction))).map(|next| self.located(next))
+    }
+
+    fn located(&self, rc: RowCol) -> (RowCol, T) {
+        (rc, self.get(rc))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn wrapping(&self) -> WrappingGrid<'_, T> {
+        WrappingGrid { grid: self }
+    }
+
+    pub fn tiled(&self) -> TiledGrid<'_, T> {
+        TiledGrid { grid: self }
+    }
+}
+
+/// The tile `rc` is in and where it falls within that tile.
+fn tile_and_location<T>(grid: &Grid<T>, rc: RowCol) -> (RowCol, RowCol) {
+    let (rows, cols) = (grid.row_count() as i64, grid.col_count() as i64);
+    let row = rc.row() - grid.min_row();
+    let col = rc.col() - grid.min_col();
+    (
+        RowCol::new(row.div_euclid(rows), col.div_euclid(cols)),
+        RowCol::new(
+            grid.min_row() + row.rem_euclid(rows),
+            grid.min_col() + col.rem_euclid(cols),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        coord::{rc, Direction},
+        grid::Grid,
+    };
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::new_offset(rc(1, 1), &lines(&["abc", "def"]));
+        let wrapping = grid.wrapping();
+        assert_eq!(wrapping.wrap(rc(1, 1)), rc(1, 1));
+        assert_eq!(wrapping.wrap(rc(0, 0)), rc(2, 3));
+        assert_eq!(wrapping.wrap(rc(3, 4)), rc(1, 1));
+        assert_eq!(wrapping.wrap(rc(-5, 11)), rc(1, 2));
+        assert_eq!(wrapping.get(rc(0, 4)), b'd');
+
+        let neighbors = wrapping.neighbors4(rc(1, 1)).collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![(rc(2, 1), b'd'), (rc(2, 1), b'd'), (rc(1, 2), b'b'), (rc(1, 3), b'c')]
+        );
+        assert_eq!(wrapping.neighbors8(rc(2, 2)).count(), 8);
+
+        let ray = wrapping.ray(rc(1, 2), Direction::E).take(4).map(|(_, b)| b).collect::<Vec<_>>();
+        assert_eq!(ray, b"cabc");
+    }
+
+    #[test]
+    fn test_tiled() {
+        let grid = Grid::new_offset(rc(1, 1), &lines(&["abc", "def"]));
+        let tiled = grid.tiled();
+        assert_eq!(tiled.tile(rc(1, 1)), rc(0, 0));
+        assert_eq!(tiled.tile(rc(2, 3)), rc(0, 0));
+        assert_eq!(tiled.tile(rc(0, 0)), rc(-1, -1));
+        assert_eq!(tiled.tile(rc(3, 7)), rc(1, 2));
+        assert_eq!(tiled.location_in_tile(rc(3, 7)), rc(1, 1));
+        assert_eq!(tiled.get(rc(-5, 11)), b'b');
+
+        let neighbors = tiled.neighbors4(rc(1, 1)).collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![(rc(0, 1), b'd'), (rc(2, 1), b'd'), (rc(1, 2), b'b'), (rc(1, 0), b'c')]
+        );
+
+        let ray = tiled.ray(rc(1, 1), Direction::N).take(3).collect::<Vec<_>>();
+        assert_eq!(ray, vec![(rc(0, 1), b'd'), (rc(-1, 1), b'a'), (rc(-2, 1), b'd')]);
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    any::Any,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    jobs::STACK_SIZE,
+    logging::{capture, replay},
+    report::Status,
+    AocError, DailyInput, PartFn,
+};
+
+/// How running a part ended.
+#[derive(Debug)]
+pub enum Outcome {
+    Ok(String),
+    Error(AocError),
+    Panic(String),
+    Timeout(Duration),
+}
+
+impl Outcome {
+    pub fn status(&self) -> Status {
+        match self {
+            Self::Ok(_) => Status::Ok,
+            Self::Error(_) => Status::Error,
+            Self::Panic(_) => Status::Panic,
+            Self::Timeout(_) => Status::Timeout,
+        }
+    }
+
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            Self::Ok(answer) => Some(answer),
+            _ => None,
+        }
+    }
+
+    /// What went wrong, for anything other than [Outcome::Ok].
+    pub fn error(&self) -> Option<String> {
+        match self {
+            Self::Ok(_) => None,
+            Self::Error(e) => Some(e.to_string()),
+            Self::Panic(message) => Some(message.clone()),
+            Self::Timeout(timeout) => Some(format!("Didn't finish within {:?}", timeout)),
+        }
+    }
+}
+
+/// Pulls `--timeout SECS` or `--timeout=SECS` out of the command line, returning the timeout (none by default) along
+/// with the remaining args.
+pub fn timeout_from_args(args: &[String]) -> (Option<Duration>, Vec<String>) {
+    let mut timeout = None;
+    let mut remaining = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = if arg == "--timeout" {
+            args.next().map(|value| value.as_str())
+        } else if let Some(value) = arg.strip_prefix("--timeout=") {
+            Some(value)
+        } else {
+            remaining.push(arg.clone());
+            continue;
+        };
+        timeout = match value.and_then(|value| value.parse::<f64>().ok()) {
+            Some(secs) if secs > 0.0 => Some(Duration::from_secs_f64(secs)),
+            _ => panic!("Invalid arg {}.  Expected a number of seconds greater than 0", arg),
+        };
+    }
+    (timeout, remaining)
+}
+
+/// Runs a part so that a panic or, when there's a `timeout`, a part that runs too long is reported instead of taking
+/// the rest of the run down with it.
+///
+/// Threads can't be stopped from the outside, so a part that times out is left running in the background until the
+/// process exits.
+pub fn run_guarded(function: PartFn, input: DailyInput, timeout: Option<Duration>) -> Outcome {
+    let Some(timeout) = timeout else {
+        return run_catching_panics(function, input);
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(format!("day{:02}", input.day))
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            // the receiver is gone if the part timed out, which is fine
+            let _ = sender.send(capture(|| run_catching_panics(function, input)));
+        });
+    if let Err(e) = spawned {
+        return Outcome::Error(e.into());
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok((outcome, logs)) => {
+            replay(&logs);
+            outcome
+        }
+        Err(_) => Outcome::Timeout(timeout),
+    }
+}
+
+fn run_catching_panics(function: PartFn, input: DailyInput) -> Outcome {
+    match catch_unwind(AssertUnwindSafe(|| function(input))) {
+        Ok(Ok(answer)) => Outcome::Ok(answer),
+        Ok(Err(e)) => Outcome::Error(e),
+        Err(payload) => Outcome::Panic(panic_message(payload)),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Panicked without a message".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::{run_guarded, timeout_from_args, Outcome};
+    use crate::{AocError, DailyInput};
+
+    fn answer(_input: DailyInput) -> Result<String, AocError> {
+        Ok("42".to_string())
+    }
+
+    fn error(_input: DailyInput) -> Result<String, AocError> {
+        Err(AocError::ParseFailed {
+            message: "bad input".to_string(),
+        })
+    }
+
+    fn panics(_input: DailyInput) -> Result<String, AocError> {
+        todo!("diagonals")
+    }
+
+    fn slow(_input: DailyInput) -> Result<String, AocError> {
+        thread::sleep(Duration::from_secs(5));
+        Ok("late".to_string())
+    }
+
+    fn input() -> DailyInput {
+        DailyInput::from_str(1, "")
+    }
+
+    #[test]
+    fn test_outcomes() {
+        let short = Some(Duration::from_millis(200));
+        for timeout in [None, short] {
+            assert!(matches!(run_guarded(answer, input(), timeout), Outcome::Ok(a) if a == "42"));
+            assert!(matches!(run_guarded(error, input(), timeout), Outcome::Error(AocError::ParseFailed { .. })));
+            assert!(matches!(
+                run_guarded(panics, input(), timeout),
+                Outcome::Panic(message) if message == "not yet implemented: diagonals"
+            ));
+        }
+        assert!(matches!(run_guarded(slow, input(), short), Outcome::Timeout(_)));
+    }
+
+    #[test]
+    fn test_timeout_from_args() {
+        let args = ["all", "--timeout", "1.5"].map(String::from);
+        assert_eq!(
+            timeout_from_args(&args),
+            (Some(Duration::from_millis(1500)), vec!["all".to_string()])
+        );
+        assert_eq!(timeout_from_args(&["3".to_string()]), (None, vec!["3".to_string()]));
+    }
+}
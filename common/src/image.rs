@@ -0,0 +1,292 @@
+use crate::{
+    coord::{RowCol, XY},
+    grid::Grid,
+    AocError,
+};
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GRAY: Rgb = [128, 128, 128];
+pub const RED: Rgb = [220, 20, 60];
+pub const GREEN: Rgb = [34, 139, 34];
+
+/// The color to draw each byte of a [Grid] with.  Bytes without a color of their own get the default.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: HashMap<u8, Rgb>,
+    default: Rgb,
+}
+
+impl Palette {
+    pub fn new(default: Rgb) -> Self {
+        Self {
+            colors: HashMap::new(),
+            default,
+        }
+    }
+
+    pub fn with(mut self, byte: u8, color: Rgb) -> Self {
+        self.colors.insert(byte, color);
+        self
+    }
+
+    pub fn color(&self, byte: u8) -> Rgb {
+        self.colors.get(&byte).copied().unwrap_or(self.default)
+    }
+}
+
+/// Walls dark on white, which suits most of the puzzles' maps.
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::new(GRAY).with(b'.', WHITE).with(b'#', BLACK)
+    }
+}
+
+/// A picture of a grid with a pixel for each cell, addressed by the same [RowCol]s as the grid it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    min: RowCol,
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn from_grid(grid: &Grid, palette: &Palette) -> Self {
+        Self {
+            min: grid.min(),
+            width: grid.col_count(),
+            height: grid.row_count(),
+            pixels: grid.all_cell_locations_by_row_by_col().map(|rc| palette.color(grid.get(rc).unwrap())).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every pixel's color, a row at a time.
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    pub fn get(&self, rc: RowCol) -> Option<Rgb> {
+        self.index_of(rc).map(|index| self.pixels[index])
+    }
+
+    /// Colors `rc`, if it's in the picture.
+    pub fn set(&mut self, rc: RowCol, color: Rgb) {
+        if let Some(index) = self.index_of(rc) {
+            self.pixels[index] = color;
+        }
+    }
+
+    /// Colors every cell the path passes through.  Steps between points in the path can be in any of the eight
+    /// directions and can cover more than one cell, like the corners of a polygon.
+    pub fn draw_path(&mut self, path: &[RowCol], color: Rgb) {
+        for rc in cells_along(path) {
+            self.set(rc, color);
+        }
+    }
+
+    /// Each cell blown up to a `factor` by `factor` square, since a pixel per cell is hard to see on most inputs.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let width = self.width * factor;
+        let height = self.height * factor;
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (y / factor) * self.width + x / factor))
+            .map(|index| self.pixels[index])
+            .collect();
+        Self {
+            min: self.min,
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Writes a binary (`P6`) PPM, which needs nothing to write but isn't opened by everything.
+    pub fn write_ppm(&self, path: impl AsRef<Path>) -> Result<(), AocError> {
+        let mut file = BufWriter::new(File::create(path)?);
+        write!(file, "P6\n{} {}\n255\n", self.width, self.height)?;
+        file.write_all(self.pixels.as_flattened())?;
+        file.flush()?;
+        Ok(())
+    }
+
+    pub fn write_png(&self, path: impl AsRef<Path>) -> Result<(), AocError> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut encoder = png::Encoder::new(file, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(png_error)?;
+        writer.write_image_data(self.pixels.as_flattened()).map_err(png_error)?;
+        writer.finish().map_err(png_error)?;
+        Ok(())
+    }
+
+    fn index_of(&self, rc: RowCol) -> Option<usize> {
+        let row = usize::try_from(rc.row() - self.min.row()).ok()?;
+        let col = usize::try_from(rc.col() - self.min.col()).ok()?;
+        (row < self.height && col < self.width).then_some(row * self.width + col)
+    }
+}
+
+impl Grid {
+    pub fn to_image(&self, palette: &Palette) -> Image {
+        Image::from_grid(self, palette)
+    }
+
+    pub fn write_ppm(&self, path: impl AsRef<Path>, palette: &Palette) -> Result<(), AocError> {
+        self.to_image(palette).write_ppm(path)
+    }
+
+    pub fn write_png(&self, path: impl AsRef<Path>, palette: &Palette) -> Result<(), AocError> {
+        self.to_image(palette).write_png(path)
+    }
+}
+
+/// Every cell from the start of `path` to its end, stepping one cell at a time towards each point in turn.  Steps
+/// that aren't straight or diagonal go diagonally until they line up and then straight the rest of the way.
+pub fn cells_along(path: &[RowCol]) -> Vec<RowCol> {
+    let mut cells = path.first().copied().into_iter().collect::<Vec<_>>();
+    for to in path.iter().skip(1) {
+        let mut at = *cells.last().unwrap();
+        while at != *to {
+            at = at.offset((to.row() - at.row()).signum(), (to.col() - at.col()).signum());
+            cells.push(at);
+        }
+    }
+    cells
+}
+
+/// An SVG drawing of each polygon, outlined over a light fill, scaled to fit whatever it's viewed in.
+pub fn polygons_to_svg(polygons: &[Vec<XY>]) -> String {
+    let points = polygons.iter().flatten();
+    let min_x = points.clone().map(|xy| xy.x()).min().unwrap_or(0);
+    let max_x = points.clone().map(|xy| xy.x()).max().unwrap_or(0);
+    let min_y = points.clone().map(|xy| xy.y()).min().unwrap_or(0);
+    let max_y = points.map(|xy| xy.y()).max().unwrap_or(0);
+    let margin = ((max_x - min_x).max(max_y - min_y) / 50).max(1);
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+        min_x - margin,
+        min_y - margin,
+        max_x - min_x + 2 * margin,
+        max_y - min_y + 2 * margin
+    );
+    svg.push('\n');
+    for polygon in polygons {
+        let points = polygon.iter().map(|xy| format!("{},{}", xy.x(), xy.y())).collect::<Vec<_>>().join(" ");
+        writeln!(
+            svg,
+            r#"  <polygon points="{points}" fill="lightsteelblue" stroke="black" stroke-width="1" vector-effect="non-scaling-stroke"/>"#
+        )
+        .unwrap();
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+pub fn write_svg(path: impl AsRef<Path>, polygons: &[Vec<XY>]) -> Result<(), AocError> {
+    std::fs::write(path, polygons_to_svg(polygons))?;
+    Ok(())
+}
+
+fn png_error(e: png::EncodingError) -> AocError {
+    AocError::Io {
+        source: std::io::Error::other(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        coord::{rc, xy},
+        grid::Grid,
+        image::{cells_along, polygons_to_svg, Image, Palette, BLACK, GRAY, RED, WHITE},
+    };
+    use std::{env, fs};
+
+    fn lines(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|l| l.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = Grid::new_offset(rc(1, 1), &lines(&["#.", ".S"]));
+        let image = Image::from_grid(&grid, &Palette::default().with(b'S', RED));
+        assert_eq!((image.width(), image.height()), (2, 2));
+        assert_eq!(image.get(rc(1, 1)), Some(BLACK));
+        assert_eq!(image.get(rc(1, 2)), Some(WHITE));
+        assert_eq!(image.get(rc(2, 2)), Some(RED));
+        assert_eq!(image.get(rc(0, 0)), None);
+
+        let scaled = image.scaled(3);
+        assert_eq!((scaled.width(), scaled.height()), (6, 6));
+        assert_eq!(scaled.get(rc(3, 3)), Some(BLACK));
+        assert_eq!(scaled.get(rc(1, 4)), Some(WHITE));
+        assert_eq!(scaled.get(rc(4, 4)), Some(RED));
+    }
+
+    #[test]
+    fn test_draw_path() {
+        assert_eq!(
+            cells_along(&[rc(0, 0), rc(2, 2), rc(2, 0), rc(3, 3)]),
+            vec![
+                rc(0, 0),
+                rc(1, 1),
+                rc(2, 2),
+                rc(2, 1),
+                rc(2, 0),
+                rc(3, 1),
+                rc(3, 2),
+                rc(3, 3)
+            ]
+        );
+
+        let mut image = Image::from_grid(&Grid::new_repeating(rc(0, 0), rc(2, 2), b'x'), &Palette::default());
+        image.draw_path(&[rc(0, 2), rc(2, 0)], RED);
+        assert_eq!(image.get(rc(1, 1)), Some(RED));
+        assert_eq!(image.get(rc(0, 0)), Some(GRAY));
+    }
+
+    #[test]
+    fn test_write() {
+        let grid = Grid::new(&lines(&["#.", ".#"]));
+        let dir = env::temp_dir();
+
+        let ppm = dir.join("aoc-common-test_write.ppm");
+        grid.write_ppm(&ppm, &Palette::default()).unwrap();
+        let bytes = fs::read(&ppm).unwrap();
+        assert!(bytes.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(bytes.len(), 11 + 2 * 2 * 3);
+        fs::remove_file(ppm).unwrap();
+
+        let png = dir.join("aoc-common-test_write.png");
+        grid.write_png(&png, &Palette::default()).unwrap();
+        assert!(fs::read(&png).unwrap().starts_with(b"\x89PNG"));
+        fs::remove_file(png).unwrap();
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = polygons_to_svg(&[vec![xy(0, 0), xy(100, 0), xy(100, 50)]]);
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-2 -2 104 54">"#));
+        assert!(svg.contains(r#"points="0,0 100,0 100,50""#));
+    }
+}
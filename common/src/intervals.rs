@@ -0,0 +1,401 @@
+use num::PrimInt;
+use std::ops::{Range, RangeInclusive};
+
+/// A set of integers kept as the ranges they make up, for when there are far too many to keep one at a time.  Ranges
+/// are half open like [Range], and are kept in order without any overlapping or touching each other, so there's only
+/// one way to write down each set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The ranges in the set, lowest first.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The lowest value in the set.
+    pub fn first(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    /// The highest value in the set.
+    pub fn last(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::one())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(index).is_some_and(|range| range.start <= value)
+    }
+
+    /// How many values are in the set.
+    pub fn total_length(&self) -> T {
+        self.ranges.iter().fold(T::zero(), |total, range| total + (range.end - range.start))
+    }
+
+    /// Adds every value in `range`, joining it up with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let from = self.ranges.partition_point(|existing| existing.end < range.start);
+        let to = self.ranges.partition_point(|existing| existing.start <= range.end);
+        let joined = match from < to {
+            true => range.start.min(self.ranges[from].start)..range.end.max(self.ranges[to - 1].end),
+            false => range,
+        };
+        self.ranges.splice(from..to, [joined]);
+    }
+
+    /// Takes out every value in `range`, cutting back or splitting any ranges it overlaps.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let from = self.ranges.partition_point(|existing| existing.end <= range.start);
+        let to = self.ranges.partition_point(|existing| existing.start < range.end);
+        if from == to {
+            return;
+        }
+        let before = self.ranges[from].start..range.start;
+        let after = range.end..self.ranges[to - 1].end;
+        let left = [before, after].into_iter().filter(|piece| !piece.is_empty()).collect::<Vec<_>>();
+        self.ranges.splice(from..to, left);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.ranges.iter().for_each(|range| union.insert(range.clone()));
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            match a.end < b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Everything in this set that isn't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        other.ranges.iter().for_each(|range| difference.remove(range.clone()));
+        difference
+    }
+
+    /// Whether every value in this set is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Whether any value is in both sets.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// The values below `value`, and `value` and everything above it.
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let (mut below, mut above) = (vec![], vec![]);
+        for range in &self.ranges {
+            if range.end <= value {
+                below.push(range.clone());
+            } else if range.start >= value {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..value);
+                above.push(value..range.end);
+            }
+        }
+        (Self { ranges: below }, Self { ranges: above })
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::from_iter([range])
+    }
+}
+
+impl<T: PrimInt> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::from_iter([range])
+    }
+}
+
+impl<T: PrimInt> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// For the puzzles that give ranges with both ends included.  A half open range can't end past `T::max_value()`, so
+/// that one value is never in the set: a range ending at it stops just short of it instead.
+impl<T: PrimInt> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        iter.into_iter()
+            .map(|range| *range.start()..range.end().checked_add(&T::one()).unwrap_or(*range.end()))
+            .collect()
+    }
+}
+
+impl<T: PrimInt> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+/// A value for each of a number of ranges of keys, like an offset that applies across a whole range.  Ranges are half
+/// open and never overlap, so inserting over part of a range that's already there cuts it back to what's left of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalMap<T, V> {
+    entries: Vec<(Range<T>, V)>,
+}
+
+impl<T, V> Default for IntervalMap<T, V> {
+    fn default() -> Self {
+        Self { entries: vec![] }
+    }
+}
+
+impl<T: PrimInt, V: Clone> IntervalMap<T, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Sets the value for every key in `range`, replacing whatever was there before.
+    pub fn insert(&mut self, range: Range<T>, value: V) {
+        if range.is_empty() {
+            return;
+        }
+        let from = self.entries.partition_point(|(existing, _)| existing.end <= range.start);
+        let to = self.entries.partition_point(|(existing, _)| existing.start < range.end);
+        let mut replacement = vec![];
+        if from < to {
+            let (first, first_value) = &self.entries[from];
+            if first.start < range.start {
+                replacement.push((first.start..range.start, first_value.clone()));
+            }
+        }
+        replacement.push((range.clone(), value));
+        if from < to {
+            let (last, last_value) = &self.entries[to - 1];
+            if range.end < last.end {
+                replacement.push((range.end..last.end, last_value.clone()));
+            }
+        }
+        self.entries.splice(from..to, replacement);
+    }
+
+    pub fn get(&self, key: T) -> Option<&V> {
+        let index = self.entries.partition_point(|(range, _)| range.end <= key);
+        self.entries.get(index).filter(|(range, _)| range.start <= key).map(|(_, value)| value)
+    }
+
+    /// Each range and its value, lowest first.
+    pub fn iter(&self) -> impl Iterator<Item = (&Range<T>, &V)> {
+        self.entries.iter().map(|(range, value)| (range, value))
+    }
+
+    /// Every key that has a value.
+    pub fn keys(&self) -> IntervalSet<T> {
+        self.entries.iter().map(|(range, _)| range.clone()).collect()
+    }
+
+    /// `set` cut up wherever one of the map's ranges starts or ends, each piece with the value for its keys, or `None`
+    /// for the pieces without one.  Lowest first.
+    pub fn segments(&self, set: &IntervalSet<T>) -> Vec<(Range<T>, Option<&V>)> {
+        let mut segments = vec![];
+        for range in set.ranges() {
+            let mut at = range.start;
+            let first = self.entries.partition_point(|(entry, _)| entry.end <= range.start);
+            for (entry, value) in self.entries[first..].iter().take_while(|(entry, _)| entry.start < range.end) {
+                if at < entry.start {
+                    segments.push((at..entry.start, None));
+                    at = entry.start;
+                }
+                let end = entry.end.min(range.end);
+                segments.push((at..end, Some(value)));
+                at = end;
+            }
+            if at < range.end {
+                segments.push((at..range.end, None));
+            }
+        }
+        segments
+    }
+}
+
+impl<T: PrimInt, V: Clone> FromIterator<(Range<T>, V)> for IntervalMap<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Range<T>, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (range, value) in iter {
+            map.insert(range, value);
+        }
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::intervals::{IntervalMap, IntervalSet};
+    use std::ops::Range;
+
+    fn r(range: Range<i32>) -> IntervalSet<i32> {
+        IntervalSet::from(range)
+    }
+
+    fn set(ranges: &[Range<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_insert_and_remove() {
+        let mut s = set(&[10..20, 0..5, 30..40]);
+        assert_eq!(s.ranges(), &[0..5, 10..20, 30..40]);
+        s.insert(5..10);
+        assert_eq!(s.ranges(), &[0..20, 30..40]);
+        s.insert(15..35);
+        assert_eq!(s.ranges(), &[0..40]);
+        s.insert(7..7);
+        assert_eq!(s.ranges(), &[0..40]);
+
+        s.remove(10..20);
+        assert_eq!(s.ranges(), &[0..10, 20..40]);
+        s.remove(-5..1);
+        s.remove(5..25);
+        assert_eq!(s.ranges(), &[1..5, 25..40]);
+        assert_eq!(s.total_length(), 19);
+        assert_eq!((s.first(), s.last()), (Some(1), Some(39)));
+
+        assert!(s.contains(1) && s.contains(4) && s.contains(25) && s.contains(39));
+        assert!(!s.contains(0) && !s.contains(5) && !s.contains(24) && !s.contains(40));
+
+        let inclusive = [1..=3, 4..=4, 9..=9].into_iter().collect::<IntervalSet<i32>>();
+        assert_eq!(inclusive.ranges(), &[1..5, 9..10]);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_inclusive_at_max() {
+        let near_max = IntervalSet::from(u64::MAX - 2..=u64::MAX);
+        assert_eq!(near_max.ranges(), &[u64::MAX - 2..u64::MAX]);
+        assert_eq!(near_max.last(), Some(u64::MAX - 1));
+        assert!(!near_max.contains(u64::MAX));
+        assert!(IntervalSet::from(u64::MAX..=u64::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_difference() {
+        assert_eq!(r(1..10).difference(&r(-5..0)), r(1..10));
+        assert_eq!(r(1..10).difference(&r(-5..1)), r(1..10));
+        assert_eq!(r(1..10).difference(&r(-5..2)), r(2..10));
+        assert_eq!(r(1..10).difference(&r(1..2)), r(2..10));
+        assert_eq!(r(1..10).difference(&r(2..3)), set(&[1..2, 3..10]));
+        assert_eq!(r(1..10).difference(&r(2..10)), r(1..2));
+        assert_eq!(r(1..10).difference(&r(2..11)), r(1..2));
+        assert_eq!(r(1..10).difference(&r(9..12)), r(1..9));
+        assert_eq!(r(1..10).difference(&r(10..12)), r(1..10));
+        assert_eq!(r(1..10).difference(&r(11..12)), r(1..10));
+        assert_eq!(r(1..10).difference(&r(1..10)), IntervalSet::new());
+        assert_eq!(r(1..10).difference(&r(-5..12)), IntervalSet::new());
+        assert_eq!(set(&[0..3, 5..8, 10..13]).difference(&r(2..11)), set(&[0..2, 11..13]));
+    }
+
+    #[test]
+    fn test_intersection_and_union() {
+        assert_eq!(r(1..10).intersection(&r(-5..0)), IntervalSet::new());
+        assert_eq!(r(1..10).intersection(&r(-5..1)), IntervalSet::new());
+        assert_eq!(r(1..10).intersection(&r(-5..2)), r(1..2));
+        assert_eq!(r(1..10).intersection(&r(1..2)), r(1..2));
+        assert_eq!(r(1..10).intersection(&r(2..3)), r(2..3));
+        assert_eq!(r(1..10).intersection(&r(2..10)), r(2..10));
+        assert_eq!(r(1..10).intersection(&r(2..11)), r(2..10));
+        assert_eq!(r(1..10).intersection(&r(9..12)), r(9..10));
+        assert_eq!(r(1..10).intersection(&r(10..12)), IntervalSet::new());
+        assert_eq!(r(1..10).intersection(&r(11..12)), IntervalSet::new());
+        assert_eq!(
+            set(&[0..3, 5..8, 10..13]).intersection(&set(&[2..6, 7..11])),
+            set(&[2..3, 5..6, 7..8, 10..11])
+        );
+
+        assert_eq!(r(1..5).union(&r(5..7)), r(1..7));
+        assert_eq!(r(1..5).union(&r(6..7)), set(&[1..5, 6..7]));
+
+        assert!(r(2..4).is_subset(&r(1..10)));
+        assert!(!r(2..11).is_subset(&r(1..10)));
+        assert!(r(2..11).overlaps(&r(10..12)));
+        assert!(!r(2..10).overlaps(&r(10..12)));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let s = set(&[0..3, 5..8]);
+        assert_eq!(s.split_at(6), (set(&[0..3, 5..6]), r(6..8)));
+        assert_eq!(s.split_at(5), (r(0..3), r(5..8)));
+        assert_eq!(s.split_at(-1), (IntervalSet::new(), s.clone()));
+        assert_eq!(s.split_at(8), (s.clone(), IntervalSet::new()));
+    }
+
+    #[test]
+    fn test_map() {
+        let mut map = [(0..10, 'a'), (20..30, 'b')].into_iter().collect::<IntervalMap<i32, char>>();
+        map.insert(5..25, 'c');
+        assert_eq!(
+            map.iter().map(|(range, value)| (range.clone(), *value)).collect::<Vec<_>>(),
+            vec![(0..5, 'a'), (5..25, 'c'), (25..30, 'b')]
+        );
+        map.insert(10..12, 'd');
+        assert_eq!(map.get(9), Some(&'c'));
+        assert_eq!(map.get(10), Some(&'d'));
+        assert_eq!(map.get(12), Some(&'c'));
+        assert_eq!(map.get(30), None);
+        assert_eq!(map.keys(), r(0..30));
+
+        assert_eq!(
+            map.segments(&set(&[-2..1, 11..13, 28..32])),
+            vec![
+                (-2..0, None),
+                (0..1, Some(&'a')),
+                (11..12, Some(&'d')),
+                (12..13, Some(&'c')),
+                (28..30, Some(&'b')),
+                (30..32, None)
+            ]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use crate::logging::capture;
+
+/// Same as the main thread gets on Linux, since some solutions recurse deeply.
+pub(crate) const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Pulls `--jobs N` or `--jobs=N` out of the command line, returning the number of jobs (1 by default) along with the
+/// remaining args.
+pub fn jobs_from_args(args: &[String]) -> (usize, Vec<String>) {
+    let mut jobs = 1;
+    let mut remaining = vec![];
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = if arg == "--jobs" {
+            args.next().map(|value| value.as_str())
+        } else if let Some(value) = arg.strip_prefix("--jobs=") {
+            Some(value)
+        } else {
+            remaining.push(arg.clone());
+            continue;
+        };
+        jobs = match value.and_then(|value| value.parse::<usize>().ok()) {
+            Some(jobs) if jobs > 0 => jobs,
+            _ => panic!("Invalid arg {}.  Expected a number of jobs greater than 0", arg),
+        };
+    }
+    (jobs, remaining)
+}
+
+/// Runs `run` over `items` on up to `jobs` threads.  Each result is handed to `report` in the order of `items`, as soon
+/// as it and everything before it has finished, along with whatever was logged while producing it.
+///
+/// With a single job everything runs on the current thread and logging goes straight to stdout as usual.
+pub fn run_ordered<T: Sync, R: Send>(
+    jobs: usize,
+    items: &[T],
+    run: impl Fn(&T) -> R + Sync,
+    mut report: impl FnMut(&T, R, String),
+) {
+    if jobs <= 1 {
+        items.iter().for_each(|item| report(item, run(item), String::new()));
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for worker in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, run) = (&next, &run);
+            thread::Builder::new()
+                .name(format!("job-{worker}"))
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, move || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    if index >= items.len() {
+                        break;
+                    }
+                    let (result, logs) = capture(|| run(&items[index]));
+                    if sender.send((index, result, logs)).is_err() {
+                        break;
+                    }
+                })
+                .expect("Unable to start job thread");
+        }
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut next_to_report = 0;
+        for (index, result, logs) in receiver {
+            finished.insert(index, (result, logs));
+            while let Some((result, logs)) = finished.remove(&next_to_report) {
+                report(&items[next_to_report], result, logs);
+                next_to_report += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{thread, time::Duration};
+
+    use super::{jobs_from_args, run_ordered};
+
+    #[test]
+    fn test_jobs_from_args() {
+        let args = ["all", "--jobs", "4"].map(String::from);
+        assert_eq!(jobs_from_args(&args), (4, vec!["all".to_string()]));
+
+        let args = ["--jobs=2", "3,1"].map(String::from);
+        assert_eq!(jobs_from_args(&args), (2, vec!["3,1".to_string()]));
+
+        assert_eq!(jobs_from_args(&["3".to_string()]), (1, vec!["3".to_string()]));
+    }
+
+    #[test]
+    fn test_run_ordered() {
+        let items = (0..20_u64).collect::<Vec<_>>();
+        for jobs in [1, 4] {
+            let mut reported = vec![];
+            run_ordered(
+                jobs,
+                &items,
+                |i| {
+                    // later items finish first
+                    thread::sleep(Duration::from_millis(20 - i));
+                    i * 10
+                },
+                |i, result, _| reported.push((*i, result)),
+            );
+            assert_eq!(reported, items.iter().map(|i| (*i, i * 10)).collect::<Vec<_>>());
+        }
+    }
+}
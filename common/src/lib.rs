@@ -0,0 +1,321 @@
+use std::{
+    fs::File,
+    io::{stdin, BufRead, BufReader, Error, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
+
+use flexi_logger::{Logger, LoggerHandle};
+use params::Params;
+use thiserror::Error;
+
+pub mod algo;
+pub mod answers;
+pub mod bench;
+pub mod bit_grid;
+pub mod cli;
+pub mod coord;
+pub mod grid;
+pub mod grid_view;
+pub mod guard;
+pub mod image;
+pub mod intervals;
+pub mod jobs;
+pub mod logging;
+pub mod params;
+pub mod parse;
+pub mod recorder;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod sparse_grid;
+
+pub type PartFn = fn(DailyInput) -> Result<String, AocError>;
+
+pub struct DayPartFn {
+    pub day: usize,
+    pub part: usize,
+    pub function: PartFn,
+}
+impl DayPartFn {
+    pub fn new(day: usize, part: usize, function: PartFn) -> Self {
+        Self { day, part, function }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum InputType {
+    Example,
+    Challenge,
+}
+/// Where a [DailyInput] reads its text from.
+#[derive(Clone, Debug, Default)]
+pub enum InputSource {
+    /// The year's own files for the day, picked using `input_type` and `number`.
+    #[default]
+    DayFiles,
+    /// An explicit file anywhere on disk, like someone else's challenge input.
+    Path(PathBuf),
+    /// Input that's already been read, like stdin.
+    Bytes(Arc<[u8]>),
+}
+impl InputSource {
+    /// `-` reads all of stdin up front so the input can be handed to more than one part.
+    pub fn from_arg(arg: &str) -> Result<Self, Error> {
+        if arg == "-" {
+            let mut buffer = vec![];
+            stdin().read_to_end(&mut buffer)?;
+            Ok(Self::Bytes(buffer.into()))
+        } else {
+            Ok(Self::Path(PathBuf::from(arg)))
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct DailyInput {
+    pub day: usize,
+    pub input_type: InputType,
+    pub number: Option<usize>,
+    pub source: InputSource,
+    pub params: Params,
+    /// The year's crate directory, which the day's own input files are found under.  Empty means the working
+    /// directory, which is the crate directory under `cargo test`.
+    pub dir: PathBuf,
+}
+impl DailyInput {
+    /// The day's first input file of the given type.
+    pub fn new(day: usize, input_type: InputType) -> Self {
+        Self {
+            day,
+            input_type,
+            number: None,
+            source: InputSource::DayFiles,
+            params: Params::default(),
+            dir: PathBuf::new(),
+        }
+    }
+
+    /// One of the day's other input files of the given type, for days with more than one example.
+    pub fn numbered(day: usize, input_type: InputType, number: usize) -> Self {
+        Self {
+            number: Some(number),
+            ..Self::new(day, input_type)
+        }
+    }
+
+    /// Input written inline, mostly for small inputs in tests.  It's treated as an example so parts that size things
+    /// by input type pick their example sizes.
+    pub fn from_str(day: usize, text: &str) -> Self {
+        Self {
+            day,
+            input_type: InputType::Example,
+            number: None,
+            source: InputSource::Bytes(text.as_bytes().into()),
+            params: Params::default(),
+            dir: PathBuf::new(),
+        }
+    }
+
+    /// A param given explicitly, otherwise from the input's sidecar `.params.toml` file, otherwise the day's default
+    /// for the input type.
+    pub fn param<T: FromStr>(&self, key: &str, example_default: T, challenge_default: T) -> Result<T, AocError> {
+        if let Some(value) = self.params.get(key)? {
+            return Ok(value);
+        }
+        if let Some(sidecar) = self.params_file_name() {
+            if let Some(value) = Params::load(&sidecar)?.get(key)? {
+                return Ok(value);
+            }
+        }
+        Ok(match self.input_type {
+            InputType::Example => example_default,
+            InputType::Challenge => challenge_default,
+        })
+    }
+
+    fn params_file_name(&self) -> Option<PathBuf> {
+        let input_file_name = match &self.source {
+            InputSource::DayFiles => self.find_input_file_name().ok()?,
+            InputSource::Path(path) => path.clone(),
+            InputSource::Bytes(_) => return None,
+        };
+        Some(input_file_name.with_extension("params.toml"))
+    }
+
+    /// Each year lays out its input files a little differently.  Paths are relative to the year's crate directory.
+    fn candidate_file_names(&self) -> Vec<PathBuf> {
+        let day = format!("day{:02}", self.day);
+        let qualifier = match self.input_type {
+            InputType::Example => "example",
+            InputType::Challenge => "challenge",
+        };
+        let file_names = match self.number {
+            Some(number) => vec![
+                format!("src/{day}/.input-{qualifier}-{day}-{number}.txt"),
+                format!("src/{day}/inputs/{day}-{qualifier}{number}.txt"),
+            ],
+            None => vec![
+                format!("src/{day}/.input-{qualifier}-{day}.txt"),
+                format!("src/{day}/inputs/{day}-{qualifier}.txt"),
+                match self.input_type {
+                    InputType::Example => format!("inputs/{day}-example-input.txt"),
+                    InputType::Challenge => format!("inputs/{day}-input.txt"),
+                },
+            ],
+        };
+        file_names.into_iter().map(|file_name| self.dir.join(file_name)).collect()
+    }
+
+    fn find_input_file_name(&self) -> Result<PathBuf, Error> {
+        let file_names = self.candidate_file_names();
+        match file_names.iter().find(|file_name| file_name.exists()) {
+            Some(file_name) => Ok(file_name.clone()),
+            None => Err(Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Unable to find any of {:?}", file_names),
+            )),
+        }
+    }
+
+    fn get_input_file(&self) -> Result<File, Error> {
+        self.open_input_file(&self.find_input_file_name()?)
+    }
+
+    fn open_input_file(&self, file_name: &Path) -> Result<File, Error> {
+        match File::open(file_name) {
+            Ok(f) => Ok(f),
+            Err(e) => Err(Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Unable to find file {}: {:?}", file_name.display(), e),
+            )),
+        }
+    }
+
+    fn get_reader(&self) -> Result<Box<dyn BufRead + '_>, Error> {
+        Ok(match &self.source {
+            InputSource::DayFiles => Box::new(BufReader::new(self.get_input_file()?)),
+            InputSource::Path(path) => Box::new(BufReader::new(self.open_input_file(path)?)),
+            InputSource::Bytes(bytes) => Box::new(&bytes[..]),
+        })
+    }
+
+    pub fn get_input_lines(&self) -> Result<Vec<String>, Error> {
+        self.get_reader()?.lines().collect()
+    }
+
+    pub fn get_input_as_single_string(&self) -> Result<String, Error> {
+        let mut buffer = String::new();
+        self.get_reader()?.read_to_string(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// The input split up at blank lines, for puzzles whose input comes in parts, like a map and then the moves to
+    /// make on it.
+    pub fn sections(&self) -> Result<Vec<String>, Error> {
+        Ok(parse::sections(&self.get_input_as_single_string()?))
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum AocError {
+    #[error(transparent)]
+    Parse {
+        #[from]
+        source: nom::error::Error<&'static str>,
+    },
+
+    #[error("Parse didn't succeed: {message} ")]
+    ParseFailed { message: String },
+
+    #[error("Parse didn't read all input: {remaining}")]
+    ParseNotComplete { remaining: String },
+
+    #[error(transparent)]
+    Log {
+        #[from]
+        source: flexi_logger::FlexiLoggerError,
+    },
+
+    #[error(transparent)]
+    Io {
+        #[from]
+        source: std::io::Error,
+    },
+
+    #[error(transparent)]
+    Toml {
+        #[from]
+        source: toml::de::Error,
+    },
+
+    #[error("Invalid value '{value}' for param {key}")]
+    InvalidParam { key: String, value: String },
+
+    #[error("{rc} is outside of {min}-{max}")]
+    OutOfRange {
+        rc: coord::RowCol,
+        min: coord::RowCol,
+        max: coord::RowCol,
+    },
+
+    #[error(transparent)]
+    Json {
+        #[from]
+        source: serde_json::Error,
+    },
+}
+
+pub fn enable_logging() -> Result<LoggerHandle, AocError> {
+    Logger::try_with_env_or_str("info")?
+        .log_to_writer(Box::new(logging::CapturingWriter))
+        .start()
+        .map_err(|e| e.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{DailyInput, InputType};
+
+    #[test]
+    fn test_from_str() {
+        let input = DailyInput::from_str(1, "1abc2\npqr3stu8vwx\n");
+        assert_eq!(input.get_input_lines().unwrap(), vec!["1abc2", "pqr3stu8vwx"]);
+        assert_eq!(input.get_input_as_single_string().unwrap(), "1abc2\npqr3stu8vwx\n");
+    }
+
+    #[test]
+    fn test_param() {
+        let mut input = DailyInput::from_str(11, "125 17");
+        assert_eq!(input.param("blinks", 6, 25).unwrap(), 6);
+        input.input_type = InputType::Challenge;
+        assert_eq!(input.param("blinks", 6, 25).unwrap(), 25);
+        input.params.insert("blinks", "75");
+        assert_eq!(input.param("blinks", 6, 25).unwrap(), 75);
+        input.params.insert("blinks", "many");
+        assert!(input.param("blinks", 6, 25).is_err());
+    }
+
+    #[test]
+    fn test_dir() {
+        let dir = env::temp_dir().join(format!("aoc-common-test-dir-{}", process::id()));
+        fs::create_dir_all(dir.join("src/day03/inputs")).unwrap();
+        fs::write(dir.join("src/day03/inputs/day03-example.txt"), "467..114..\n").unwrap();
+        fs::write(dir.join("src/day03/inputs/day03-example.params.toml"), "rows = 1\n").unwrap();
+
+        let input = DailyInput {
+            dir: dir.clone(),
+            ..DailyInput::new(3, InputType::Example)
+        };
+        let lines = input.get_input_lines();
+        let rows = input.param("rows", 10, 140);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(lines.unwrap(), vec!["467..114.."]);
+        assert_eq!(rows.unwrap(), 1);
+        assert!(DailyInput::new(3, InputType::Example).get_input_lines().is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    cell::RefCell,
+    io::{stderr, stdout, Write},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use flexi_logger::{default_format, writers::LogWriter, DeferredNow};
+use log::Record;
+
+static TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
+}
+
+/// Writes log lines to stdout, unless the current thread is inside [capture], in which case they're held back so they
+/// can be printed alongside the part that logged them instead of interleaving with parts on other threads.
+pub(crate) struct CapturingWriter;
+
+impl LogWriter for CapturingWriter {
+    fn write(&self, now: &mut DeferredNow, record: &Record) -> std::io::Result<()> {
+        CAPTURED.with_borrow_mut(|captured| match captured {
+            Some(buffer) => {
+                default_format(buffer, now, record)?;
+                buffer.write_all(b"\n")
+            }
+            None if TO_STDERR.load(Ordering::Relaxed) => {
+                let mut out = stderr().lock();
+                default_format(&mut out, now, record)?;
+                out.write_all(b"\n")
+            }
+            None => {
+                let mut out = stdout().lock();
+                default_format(&mut out, now, record)?;
+                out.write_all(b"\n")
+            }
+        })
+    }
+
+    fn flush(&self) -> std::io::Result<()> {
+        stdout().flush()?;
+        stderr().flush()
+    }
+}
+
+/// Sends log lines to stderr instead of stdout, for when stdout is being read by another program.
+pub fn log_to_stderr(enabled: bool) {
+    TO_STDERR.store(enabled, Ordering::Relaxed);
+}
+
+/// Passes along lines captured on another thread as if they'd been logged on this one.
+pub(crate) fn replay(logs: &str) {
+    if logs.is_empty() {
+        return;
+    }
+    CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(buffer) => buffer.extend_from_slice(logs.as_bytes()),
+        None if TO_STDERR.load(Ordering::Relaxed) => eprint!("{logs}"),
+        None => print!("{logs}"),
+    })
+}
+
+/// Runs `f`, returning its result along with anything it logged on this thread.
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, String) {
+    let previous = CAPTURED.replace(Some(vec![]));
+    let result = f();
+    let captured = CAPTURED.replace(previous).unwrap_or_default();
+    (result, String::from_utf8_lossy(&captured).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use flexi_logger::{writers::LogWriter, DeferredNow};
+    use log::{Level, Record};
+
+    use super::{capture, replay, CapturingWriter};
+
+    #[test]
+    fn test_capture() {
+        let ((), captured) = capture(|| {
+            CapturingWriter
+                .write(
+                    &mut DeferredNow::new(),
+                    &Record::builder()
+                        .level(Level::Info)
+                        .module_path(Some("aoc2024::day12"))
+                        .args(format_args!("found {} regions", 3))
+                        .build(),
+                )
+                .unwrap();
+        });
+        assert_eq!(captured, "INFO [aoc2024::day12] found 3 regions\n");
+
+        let ((), captured) = capture(|| replay("INFO [aoc2024::day12] from another thread\n"));
+        assert_eq!(captured, "INFO [aoc2024::day12] from another thread\n");
+    }
+}
@@ -0,0 +1,94 @@
+use std::{collections::BTreeMap, fs, path::Path, str::FromStr};
+
+use crate::AocError;
+
+/// Named values that tune a run without touching the puzzle input, like how many times to blink or which row to scan.
+/// Values are kept as text and parsed into whatever type the day asks for.
+///
+/// They come from `--param key=value` on the command line or from a sidecar file next to the input file, named like
+/// the input but ending in `.params.toml`:
+///
+/// ```toml
+/// blinks = 75
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    /// A missing file is treated as no params.
+    pub fn load(file_name: &Path) -> Result<Self, AocError> {
+        match fs::read_to_string(file_name) {
+            Ok(toml) => Self::parse(&toml),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn parse(toml: &str) -> Result<Self, AocError> {
+        let table: toml::Table = toml::from_str(toml)?;
+        Ok(Self(
+            table
+                .into_iter()
+                .map(|(key, value)| match value {
+                    toml::Value::String(s) => (key, s),
+                    other => (key, other.to_string()),
+                })
+                .collect(),
+        ))
+    }
+
+    /// Parses `key=value`.
+    pub fn insert_arg(&mut self, arg: &str) -> Result<(), AocError> {
+        match arg.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                self.insert(key.trim(), value.trim());
+                Ok(())
+            }
+            _ => Err(AocError::InvalidParam {
+                key: arg.to_string(),
+                value: String::new(),
+            }),
+        }
+    }
+
+    pub fn insert(&mut self, key: &str, value: &str) {
+        self.0.insert(key.to_string(), value.to_string());
+    }
+
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, AocError> {
+        match self.0.get(key) {
+            Some(value) => match value.parse::<T>() {
+                Ok(v) => Ok(Some(v)),
+                Err(_) => Err(AocError::InvalidParam {
+                    key: key.to_string(),
+                    value: value.clone(),
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Params;
+
+    #[test]
+    fn test_parse() {
+        let params = Params::parse("blinks = 75\nname = \"x\"\nscale = 1.5").unwrap();
+        assert_eq!(params.get::<usize>("blinks").unwrap(), Some(75));
+        assert_eq!(params.get::<String>("name").unwrap(), Some("x".to_string()));
+        assert_eq!(params.get::<f64>("scale").unwrap(), Some(1.5));
+        assert_eq!(params.get::<usize>("missing").unwrap(), None);
+        assert!(params.get::<usize>("name").is_err());
+    }
+
+    #[test]
+    fn test_insert_arg() {
+        let mut params = Params::default();
+        params.insert_arg("row=10").unwrap();
+        assert_eq!(params.get::<i64>("row").unwrap(), Some(10));
+        assert!(params.insert_arg("row").is_err());
+        assert!(params.insert_arg("=10").is_err());
+    }
+}
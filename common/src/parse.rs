@@ -0,0 +1,35 @@
+use nom::{bytes::complete::tag, character::complete::one_of, multi::many0, sequence::tuple, IResult};
+
+pub fn blank_line(input: &str) -> IResult<&str, ()> {
+    tuple((many0(one_of(" \t")), tag("\n")))(input).map(|(input, _)| (input, ()))
+}
+
+/// The chunks of `text` between blank lines, without the blank lines.  Runs of blank lines count as one.
+pub fn sections(text: &str) -> Vec<String> {
+    let mut sections = vec![];
+    let mut section: Vec<&str> = vec![];
+    for line in text.lines() {
+        if !line.trim().is_empty() {
+            section.push(line);
+        } else if !section.is_empty() {
+            sections.push(section.join("\n"));
+            section.clear();
+        }
+    }
+    if !section.is_empty() {
+        sections.push(section.join("\n"));
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::sections;
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(sections("ab\ncd\n\nef\r\n  \n\n\ngh\n"), vec!["ab\ncd", "ef", "gh"]);
+        assert_eq!(sections("\nab"), vec!["ab"]);
+        assert!(sections("").is_empty());
+    }
+}
@@ -0,0 +1,387 @@
+use crate::{
+    coord::RowCol,
+    grid::Grid,
+    image::{Palette, Rgb},
+    AocError, DailyInput,
+};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{stderr, stdin, BufRead, BufWriter, Write},
+    path::PathBuf,
+    sync::mpsc::{channel, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+const DEFAULT_FRAME_BUDGET: usize = 1000;
+const DEFAULT_DELAY_MS: u64 = 100;
+const DEFAULT_SCALE: usize = 4;
+
+/// Where recorded frames end up once the run's over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Playback {
+    /// Nothing's kept and snapshots are never taken.
+    Off,
+    /// An animated GIF, with each cell drawn as a `scale` by `scale` square.
+    Gif { path: PathBuf, scale: usize },
+    /// Played back in the terminal, with play/pause and stepping read a line at a time from stdin.
+    Replay,
+}
+
+/// Snapshots of a simulation's [Grid], taken a tick at a time, to watch it run afterwards.
+///
+/// At most `frame_budget` frames are ever held.  Once the budget's full, every other frame is dropped and from then on
+/// only every other tick is kept, so a long run ends up as evenly spaced frames across the whole run rather than just
+/// its start.
+pub struct FrameRecorder {
+    playback: Playback,
+    palette: Palette,
+    background: u8,
+    delay: Duration,
+    frame_budget: usize,
+    stride: usize,
+    ticks: usize,
+    frames: Vec<(usize, Grid)>,
+}
+
+impl FrameRecorder {
+    pub fn new(playback: Playback, frame_budget: usize) -> Self {
+        Self {
+            playback,
+            palette: Palette::default(),
+            background: b'.',
+            delay: Duration::from_millis(DEFAULT_DELAY_MS),
+            frame_budget: frame_budget.max(2),
+            stride: 1,
+            ticks: 0,
+            frames: vec![],
+        }
+    }
+
+    /// A recorder set up from the input's params, which is off unless one of these is passed:
+    ///
+    /// - `--param gif=<file>` writes an animated GIF, with `--param scale=<pixels per cell>`
+    /// - `--param replay=true` plays it back in the terminal
+    ///
+    /// `--param frames=<budget>` and `--param delay=<ms between frames>` apply to both.
+    pub fn from_input(input: &DailyInput) -> Result<Self, AocError> {
+        let playback = if let Some(path) = input.params.get::<PathBuf>("gif")? {
+            Playback::Gif {
+                path,
+                scale: input.params.get("scale")?.unwrap_or(DEFAULT_SCALE),
+            }
+        } else if input.params.get("replay")?.unwrap_or(false) {
+            Playback::Replay
+        } else {
+            Playback::Off
+        };
+        let frame_budget = input.params.get("frames")?.unwrap_or(DEFAULT_FRAME_BUDGET);
+        let delay = input.params.get("delay")?.unwrap_or(DEFAULT_DELAY_MS);
+        Ok(Self::new(playback, frame_budget).with_delay(Duration::from_millis(delay)))
+    }
+
+    pub fn with_palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// What to fill in around frames that don't cover the whole area the recording does.
+    pub fn with_background(mut self, background: u8) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.playback != Playback::Off
+    }
+
+    /// Records a tick, keeping a copy of `grid` if it's a tick that's kept.
+    pub fn record(&mut self, grid: &Grid) {
+        self.record_with(|| grid.clone());
+    }
+
+    /// Records a tick, only calling `snapshot` if it's a tick that's kept.  This is for simulations whose state isn't
+    /// a [Grid] to begin with and would be expensive to draw every tick.
+    pub fn record_with(&mut self, snapshot: impl FnOnce() -> Grid) {
+        if !self.is_recording() {
+            return;
+        }
+        let tick = self.ticks;
+        self.ticks += 1;
+        if !tick.is_multiple_of(self.stride) {
+            return;
+        }
+        if self.frames.len() == self.frame_budget {
+            self.frames = self.frames.drain(..).step_by(2).collect();
+            self.stride *= 2;
+            if !tick.is_multiple_of(self.stride) {
+                return;
+            }
+        }
+        self.frames.push((tick, snapshot()));
+    }
+
+    /// Records the end state, even if it falls on a tick that wouldn't otherwise be kept, so the recording always
+    /// finishes where the simulation did.  It goes over the frame budget by one if it has to.
+    pub fn record_final(&mut self, grid: &Grid) {
+        self.record_final_with(|| grid.clone());
+    }
+
+    /// Records the end state like [FrameRecorder::record_final], only calling `snapshot` if the frame's needed.
+    pub fn record_final_with(&mut self, snapshot: impl FnOnce() -> Grid) {
+        if !self.is_recording() {
+            return;
+        }
+        let tick = self.ticks.saturating_sub(1);
+        if self.frames.last().map(|(last, _)| *last) != Some(tick) {
+            self.frames.push((tick, snapshot()));
+        }
+    }
+
+    /// The ticks of the frames that were kept.
+    pub fn ticks(&self) -> impl Iterator<Item = usize> + '_ {
+        self.frames.iter().map(|(tick, _)| *tick)
+    }
+
+    /// Writes or plays the recording, depending on the playback it was set up with.
+    pub fn finish(self) -> Result<(), AocError> {
+        match &self.playback {
+            Playback::Off => Ok(()),
+            Playback::Gif { path, scale } => self.write_gif(File::create(path)?, *scale),
+            Playback::Replay => {
+                let (sender, commands) = channel();
+                // the thread's left blocked on stdin when playback ends, which is fine since the run's over by then
+                thread::spawn(move || {
+                    for line in stdin().lock().lines().map_while(Result::ok) {
+                        if sender.send(line).is_err() {
+                            break;
+                        }
+                    }
+                });
+                self.replay(commands, &mut stderr().lock())
+            }
+        }
+    }
+
+    /// Every frame drawn on a canvas covering all of them, so frames from a grid that grew or moved line up.
+    fn canvases(&self) -> impl Iterator<Item = (usize, Grid)> + '_ {
+        let min = self
+            .frames
+            .iter()
+            .map(|(_, g)| g.min())
+            .reduce(|a, b| RowCol::new(a.row().min(b.row()), a.col().min(b.col())));
+        let max = self
+            .frames
+            .iter()
+            .map(|(_, g)| g.max())
+            .reduce(|a, b| RowCol::new(a.row().max(b.row()), a.col().max(b.col())));
+        self.frames.iter().map(move |(tick, frame)| {
+            if frame.min() == min.unwrap() && frame.max() == max.unwrap() {
+                return (*tick, frame.clone());
+            }
+            let mut canvas = Grid::new_repeating(min.unwrap(), max.unwrap(), self.background);
+            canvas.paste(frame.min(), frame).unwrap();
+            (*tick, canvas)
+        })
+    }
+
+    fn write_gif(&self, out: impl Write, scale: usize) -> Result<(), AocError> {
+        let images =
+            self.canvases().map(|(_, canvas)| canvas.to_image(&self.palette).scaled(scale)).collect::<Vec<_>>();
+        let Some(first) = images.first() else {
+            return Ok(());
+        };
+        let (width, height) = (to_u16(first.width())?, to_u16(first.height())?);
+
+        // a palette can only map the 256 possible bytes, so there's never more colors than a GIF can index
+        let mut color_indexes = HashMap::<Rgb, u8>::new();
+        let frames = images
+            .iter()
+            .map(|image| {
+                image
+                    .pixels()
+                    .iter()
+                    .map(|color| {
+                        let next_index = color_indexes.len() as u8;
+                        *color_indexes.entry(*color).or_insert(next_index)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut global_palette = vec![[0_u8; 3]; color_indexes.len()];
+        color_indexes.iter().for_each(|(color, index)| global_palette[*index as usize] = *color);
+
+        let mut encoder =
+            gif::Encoder::new(BufWriter::new(out), width, height, global_palette.as_flattened()).map_err(gif_error)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(gif_error)?;
+        let delay = (self.delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        for pixels in frames {
+            let mut frame = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+            frame.delay = delay;
+            encoder.write_frame(&frame).map_err(gif_error)?;
+        }
+        Ok(())
+    }
+
+    /// Shows each frame in turn, taking commands a line at a time: Enter or `s` steps forward, `b` steps back, `p`
+    /// plays or pauses and `q` quits.  It starts off playing and pauses on the last frame.  If the commands run out,
+    /// it plays through to the end and stops there.
+    fn replay(&self, commands: Receiver<String>, out: &mut impl Write) -> Result<(), AocError> {
+        let canvases = self.canvases().collect::<Vec<_>>();
+        if canvases.is_empty() {
+            return Ok(());
+        }
+        let last = canvases.len() - 1;
+        let (mut index, mut playing, mut commands_ended) = (0, true, false);
+        loop {
+            let (tick, canvas) = &canvases[index];
+            self.draw(out, canvas)?;
+            writeln!(
+                out,
+                "Frame {}/{} (tick {tick})  [Enter/s] step  [b] back  [p] {}  [q] quit",
+                index + 1,
+                canvases.len(),
+                if playing { "pause" } else { "play" }
+            )?;
+            out.flush()?;
+
+            let command = if commands_ended {
+                if index == last {
+                    return Ok(());
+                }
+                thread::sleep(self.delay);
+                None
+            } else if playing && index < last {
+                match commands.recv_timeout(self.delay) {
+                    Ok(command) => Some(command),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        commands_ended = true;
+                        None
+                    }
+                }
+            } else {
+                playing = false;
+                match commands.recv() {
+                    Ok(command) => Some(command),
+                    Err(_) => {
+                        commands_ended = true;
+                        continue;
+                    }
+                }
+            };
+
+            match command.as_deref().map(str::trim) {
+                None => index = (index + 1).min(last),
+                Some("" | "s") => {
+                    playing = false;
+                    index = (index + 1).min(last);
+                }
+                Some("b") => {
+                    playing = false;
+                    index = index.saturating_sub(1);
+                }
+                Some("p") => playing = !playing,
+                Some("q") => return Ok(()),
+                Some(_) => {}
+            }
+        }
+    }
+
+    /// Clears the screen and draws each cell in its palette color.
+    fn draw(&self, out: &mut impl Write, canvas: &Grid) -> Result<(), AocError> {
+        write!(out, "\x1b[2J\x1b[H")?;
+        for row in canvas.rows() {
+            for cell in canvas.get_row(row).unwrap() {
+                let [r, g, b] = self.palette.color(cell);
+                write!(out, "\x1b[38;2;{r};{g};{b}m{}", cell as char)?;
+            }
+            writeln!(out, "\x1b[0m")?;
+        }
+        Ok(())
+    }
+}
+
+fn to_u16(size: usize) -> Result<u16, AocError> {
+    u16::try_from(size).map_err(|_| AocError::Io {
+        source: std::io::Error::other(format!("{size} pixels is too big for a GIF")),
+    })
+}
+
+fn gif_error(e: gif::EncodingError) -> AocError {
+    AocError::Io {
+        source: std::io::Error::other(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        coord::rc,
+        grid::Grid,
+        recorder::{FrameRecorder, Playback},
+    };
+    use std::{sync::mpsc::channel, time::Duration};
+
+    fn frame(tick: usize) -> Grid {
+        Grid::new_repeating(rc(0, 0), rc(0, 2), b'0' + (tick % 10) as u8)
+    }
+
+    fn recorder(frame_budget: usize) -> FrameRecorder {
+        FrameRecorder::new(Playback::Replay, frame_budget).with_delay(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_frame_budget() {
+        let mut recorder = recorder(4);
+        (0..10).for_each(|tick| recorder.record_with(|| frame(tick)));
+        assert_eq!(recorder.ticks().collect::<Vec<_>>(), vec![0, 4, 8]);
+        recorder.record_final(&frame(9));
+        assert_eq!(recorder.ticks().collect::<Vec<_>>(), vec![0, 4, 8, 9]);
+        recorder.record_final_with(|| panic!("Shouldn't snapshot a frame that's already kept"));
+
+        let mut off = FrameRecorder::new(Playback::Off, 4);
+        off.record_with(|| panic!("Shouldn't snapshot when not recording"));
+        off.record_final_with(|| panic!("Shouldn't snapshot when not recording"));
+        assert_eq!(off.ticks().count(), 0);
+    }
+
+    #[test]
+    fn test_write_gif() {
+        let mut recorder = recorder(10);
+        recorder.record(&frame(0));
+        recorder.record(&Grid::new_repeating(rc(1, 1), rc(1, 1), b'#'));
+
+        let mut gif = vec![];
+        recorder.write_gif(&mut gif, 2).unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        // the canvas covers both frames, scaled up two times
+        assert_eq!(&gif[6..10], &[6, 0, 4, 0]);
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut recorder = recorder(10);
+        (0..3).for_each(|tick| recorder.record(&frame(tick)));
+
+        let (sender, commands) = channel();
+        ["p", "s", "b", "b", "q"].iter().for_each(|c| sender.send(c.to_string()).unwrap());
+        let mut out = vec![];
+        recorder.replay(commands, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let shown = out.lines().filter_map(|l| l.strip_prefix("Frame ")).map(|l| &l[..3]).collect::<Vec<_>>();
+        assert_eq!(shown, vec!["1/3", "1/3", "2/3", "1/3", "1/3"]);
+
+        // without any commands it plays through to the end
+        let (_, commands) = channel::<String>();
+        let mut out = vec![];
+        recorder.replay(commands, &mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("Frame 3/3"));
+    }
+}
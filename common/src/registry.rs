@@ -0,0 +1,26 @@
+use std::{collections::BTreeSet, path::Path};
+
+use crate::{
+    answers::{Answers, ANSWERS_FILE},
+    DayPartFn,
+};
+
+/// A year's solutions, implemented by each year's crate so one runner can find and run any of them.
+pub trait Year: Sync {
+    fn year(&self) -> usize;
+
+    /// The year's crate directory.  Input files, answers and bench baselines are all found relative to it.
+    fn dir(&self) -> &'static Path;
+
+    fn day_parts(&self) -> Vec<DayPartFn>;
+
+    /// The last day with a solution, which is what runs when no days are asked for.  Stubs are registered too, so
+    /// this is the last day with an answer in the year's answers file, or the last day registered without one.
+    fn latest_day(&self) -> Option<usize> {
+        let days = self.day_parts().iter().map(|dp| dp.day).collect::<BTreeSet<_>>();
+        let answered = Answers::load(&self.dir().join(ANSWERS_FILE))
+            .map(|answers| answers.answered_days().filter(|day| days.contains(day)).max())
+            .unwrap_or_default();
+        answered.or_else(|| days.last().copied())
+    }
+}
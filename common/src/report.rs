@@ -0,0 +1,219 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use serde::Serialize;
+
+/// How the runners print each part's result.
+///
+/// - `text` the usual `[Day  1 Part  1] in  0m  0.000123s = 11` lines
+/// - `json` one JSON object per line
+/// - `csv` a header row, then one row per part
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("Unknown format {s}.  Expected one of json, csv or text")),
+        }
+    }
+}
+
+impl Format {
+    /// Pulls `--format F` or `--format=F` out of the command line, returning the format along with the remaining args.
+    pub fn from_args(args: &[String]) -> (Self, Vec<String>) {
+        let mut format = Self::default();
+        let mut remaining = vec![];
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let value = if arg == "--format" {
+                args.next().map(|value| value.as_str())
+            } else if let Some(value) = arg.strip_prefix("--format=") {
+                Some(value)
+            } else {
+                remaining.push(arg.clone());
+                continue;
+            };
+            format = match value.map(Self::from_str) {
+                Some(Ok(format)) => format,
+                Some(Err(message)) => panic!("Invalid arg {}.  {}", arg, message),
+                None => panic!("Invalid arg {}.  Expected json, csv or text after it", arg),
+            };
+        }
+        (format, remaining)
+    }
+
+    /// Printed once before any records, if the format needs it.
+    pub fn header(&self) -> Option<&'static str> {
+        match self {
+            Self::Csv => Some("year,day,part,input,status,answer,duration_secs,error"),
+            _ => None,
+        }
+    }
+
+    /// Whether this format is meant for people, so logs and summaries can share stdout with the results.
+    pub fn is_text(&self) -> bool {
+        *self == Self::Text
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Ok,
+    Error,
+    Panic,
+    Timeout,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Ok => "OK",
+            Self::Error => "ERROR",
+            Self::Panic => "PANIC",
+            Self::Timeout => "TIMEOUT",
+        })
+    }
+}
+
+/// The outcome of running one part.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartRecord {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub input: String,
+    pub status: Status,
+    pub answer: Option<String>,
+    #[serde(rename = "duration_secs", serialize_with = "serialize_secs")]
+    pub duration: Duration,
+    pub error: Option<String>,
+}
+
+fn serialize_secs<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+impl PartRecord {
+    /// Renders the record as a line of output.  Only the text format leaves multi-line answers as they are.
+    pub fn to_line(&self, format: Format) -> String {
+        match format {
+            Format::Text => match self.status {
+                Status::Ok => format!(
+                    "[Day {:2} Part {:2}] in {} = {}",
+                    self.day,
+                    self.part,
+                    format_duration(&self.duration),
+                    self.answer.as_deref().unwrap_or_default()
+                ),
+                status => format!(
+                    "[Day {:2} Part {:2}] in {} {}: {}",
+                    self.day,
+                    self.part,
+                    format_duration(&self.duration),
+                    status,
+                    self.error.as_deref().unwrap_or_default()
+                ),
+            },
+            Format::Json => serde_json::to_string(self).expect("Records always serialize"),
+            Format::Csv => [
+                self.year.to_string(),
+                self.day.to_string(),
+                self.part.to_string(),
+                csv_field(&self.input),
+                self.status.to_string(),
+                csv_field(self.answer.as_deref().unwrap_or_default()),
+                format!("{:.6}", self.duration.as_secs_f64()),
+                csv_field(self.error.as_deref().unwrap_or_default()),
+            ]
+            .join(","),
+        }
+    }
+}
+
+pub fn format_duration(duration: &Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    let seconds = duration.as_secs() % 60;
+    let microseconds = duration.subsec_micros();
+    format!("{:2}m {:2}.{:06}s", minutes, seconds, microseconds)
+}
+
+/// Quotes a field when it holds anything that would otherwise break the row, like the newlines in a multi-line answer.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Format, PartRecord, Status};
+
+    fn record(answer: Option<&str>, error: Option<&str>) -> PartRecord {
+        PartRecord {
+            year: 2022,
+            day: 10,
+            part: 2,
+            input: "challenge".to_string(),
+            status: if error.is_some() { Status::Panic } else { Status::Ok },
+            answer: answer.map(String::from),
+            duration: Duration::from_micros(1500),
+            error: error.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            record(Some("13220"), None).to_line(Format::Text),
+            "[Day 10 Part  2] in  0m  0.001500s = 13220"
+        );
+        assert_eq!(
+            record(None, Some("oops")).to_line(Format::Text),
+            "[Day 10 Part  2] in  0m  0.001500s PANIC: oops"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            record(Some("#..#\n.##."), None).to_line(Format::Json),
+            r##"{"year":2022,"day":10,"part":2,"input":"challenge","status":"OK","answer":"#..#\n.##.","duration_secs":0.0015,"error":null}"##
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            record(Some("#..#\n.##."), None).to_line(Format::Csv),
+            "2022,10,2,challenge,OK,\"#..#\n.##.\",0.001500,"
+        );
+        assert_eq!(
+            record(None, Some("Parse didn't succeed: \"x\", y")).to_line(Format::Csv),
+            "2022,10,2,challenge,PANIC,,0.001500,\"Parse didn't succeed: \"\"x\"\", y\""
+        );
+    }
+
+    #[test]
+    fn test_from_args() {
+        let args = ["all", "--format", "csv"].map(String::from);
+        assert_eq!(Format::from_args(&args), (Format::Csv, vec!["all".to_string()]));
+
+        let args = ["--format=json", "1"].map(String::from);
+        assert_eq!(Format::from_args(&args), (Format::Json, vec!["1".to_string()]));
+    }
+}
@@ -0,0 +1,350 @@
+use std::{
+    collections::BTreeSet,
+    io::{stdout, Write},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{Answers, Verdict, ANSWERS_FILE},
+    bench::{Baseline, BenchOptions, BenchStats},
+    cli::InputOptions,
+    guard::{run_guarded, timeout_from_args, Outcome},
+    jobs::{jobs_from_args, run_ordered},
+    logging,
+    registry::Year,
+    report::{format_duration, Format, PartRecord, Status},
+    AocError, DayPartFn, InputSource,
+};
+
+const BASELINE_FILE: &str = "bench-baseline.json";
+
+/// Runs the parts picked out by `args` for each of `years`, the same way for every year:
+///
+/// - `day[,part]...` runs those days, or just one part of a day.  With no days, the latest day runs, or every day
+///   when running more than one year.
+/// - `all` runs every day
+/// - `verify [day[,part]...]` checks answers against the year's `answers.toml`
+/// - `bench [--runs=N] [--warmup=N] [--threshold=PCT] [--save] [day[,part]...]` times parts against the year's
+///   baseline
+/// - `--example[=N] | --input path/to/file.txt | --input -` and `--param key=value` pick the input
+/// - `--jobs N`, `--timeout SECS` and `--format json|csv|text` control how parts run and report
+///
+/// Each year's input files, answers and baseline are looked up under its own directory, so it doesn't matter where
+/// this is run from.  Returns whether everything succeeded.
+pub fn run(years: &[&dyn Year], args: &[String]) -> Result<bool, AocError> {
+    let (input, args) = InputOptions::from_args(args)?;
+    let (jobs, args) = jobs_from_args(&args);
+    let (timeout, args) = timeout_from_args(&args);
+    let (format, args) = Format::from_args(&args);
+    logging::log_to_stderr(!format.is_text());
+
+    let verify = args.iter().any(|a| a == "verify");
+    let bench = args.iter().any(|a| a == "bench");
+    let (bench_options, args) = BenchOptions::from_args(&args);
+    let args = args.into_iter().filter(|a| a != "verify" && a != "bench").collect::<Vec<_>>();
+    let all = args.iter().any(|a| a == "all") || ((verify || bench || years.len() > 1) && args.is_empty());
+    let days = parse_day_parts(&args.into_iter().filter(|a| a != "all").collect::<Vec<_>>());
+
+    if let Some(header) = format.header().filter(|_| !verify && !bench) {
+        println!("{header}");
+    }
+    let mut succeeded = true;
+    for &year in years {
+        if years.len() > 1 && format.is_text() {
+            println!("===== {} =====", year.year());
+        }
+
+        let day_parts = find_parts_to_run(year, all, &days);
+        succeeded &= if verify {
+            verify_parts(year.dir(), day_parts, &input, jobs, timeout)?
+        } else if bench {
+            bench_parts(year.dir(), day_parts, &input, &bench_options, timeout)?
+        } else {
+            run_parts(year, day_parts, &input, jobs, timeout, format)
+        };
+    }
+    Ok(succeeded)
+}
+
+fn run_parts(
+    year: &dyn Year,
+    day_parts: Vec<DayPartFn>,
+    input: &InputOptions,
+    jobs: usize,
+    timeout: Option<Duration>,
+    format: Format,
+) -> bool {
+    let start_all_time = Instant::now();
+    let mut cpu_duration = Duration::ZERO;
+    let mut failed = 0;
+    run_ordered(
+        jobs,
+        &day_parts,
+        |DayPartFn { day, function, .. }| {
+            let start_time = Instant::now();
+            let outcome = run_guarded(*function, input.daily_input(year.dir(), *day), timeout);
+            (outcome, Instant::now() - start_time)
+        },
+        |DayPartFn { day, part, .. }, (outcome, duration), logs| {
+            cpu_duration += duration;
+            let record = PartRecord {
+                year: year.year(),
+                day: *day,
+                part: *part,
+                input: input.description(),
+                status: outcome.status(),
+                answer: outcome.answer().map(String::from),
+                duration,
+                error: outcome.error(),
+            };
+            if record.status != Status::Ok {
+                failed += 1;
+            }
+            if format.is_text() {
+                print!("{logs}");
+            } else {
+                eprint!("{logs}");
+            }
+            println!("{}", record.to_line(format));
+        },
+    );
+    let total_duration: Duration = Instant::now() - start_all_time;
+    let summary = format!(
+        "Total time: {} (CPU time {} across {} job(s))",
+        format_duration(&total_duration),
+        format_duration(&cpu_duration),
+        jobs
+    );
+    if format.is_text() {
+        println!("{summary}");
+    } else {
+        eprintln!("{summary}");
+    }
+    failed == 0
+}
+
+fn verify_parts(
+    dir: &Path,
+    day_parts: Vec<DayPartFn>,
+    input: &InputOptions,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Result<bool, AocError> {
+    let answers = Answers::load(&dir.join(ANSWERS_FILE))?;
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    run_ordered(
+        jobs,
+        &day_parts,
+        |DayPartFn { day, function, .. }| run_guarded(*function, input.daily_input(dir, *day), timeout),
+        |DayPartFn { day, part, .. }, outcome, logs| {
+            print!("{logs}");
+            print!("[Day {:2} Part {:2}]", day, part);
+            match outcome {
+                Outcome::Ok(answer) => match check(&answers, input, *day, *part, &answer) {
+                    Verdict::Pass => {
+                        passed += 1;
+                        println!(" PASS = {answer}");
+                    }
+                    Verdict::Fail { expected } => {
+                        failed += 1;
+                        println!(" FAIL = {answer} (expected {expected})");
+                    }
+                    Verdict::Unknown => {
+                        unknown += 1;
+                        println!(" UNKNOWN = {answer}");
+                    }
+                },
+                outcome => {
+                    failed += 1;
+                    println!(
+                        " FAIL with {}: {}",
+                        outcome.status(),
+                        outcome.error().unwrap_or_default()
+                    );
+                }
+            }
+        },
+    );
+    println!("Verified: {passed} passed, {failed} failed, {unknown} unknown");
+
+    Ok(failed == 0)
+}
+
+/// Answers are only known for the year's own input files.
+fn check(answers: &Answers, input: &InputOptions, day: usize, part: usize, answer: &str) -> Verdict {
+    match input.source {
+        InputSource::DayFiles => answers.check(day, part, &input.input_type, input.number, answer),
+        _ => Verdict::Unknown,
+    }
+}
+
+/// Each run of a part is guarded the same as any other run, so a part that fails is reported and left out of the
+/// baseline without stopping the rest.
+fn bench_parts(
+    dir: &Path,
+    day_parts: Vec<DayPartFn>,
+    input: &InputOptions,
+    options: &BenchOptions,
+    timeout: Option<Duration>,
+) -> Result<bool, AocError> {
+    let baseline_file = dir.join(BASELINE_FILE);
+    let mut baseline = Baseline::load(&baseline_file)?;
+
+    let (mut regressions, mut failed) = (0, 0);
+    'parts: for DayPartFn { day, part, function } in day_parts {
+        print!("[Day {:2} Part {:2}]", day, part);
+        stdout().flush()?;
+
+        let daily_input = input.daily_input(dir, day);
+        let mut samples = Vec::with_capacity(options.runs);
+        for run in 0..options.warmup + options.runs {
+            let start_time = Instant::now();
+            let outcome = run_guarded(function, daily_input.clone(), timeout);
+            let duration = Instant::now() - start_time;
+            if outcome.status() != Status::Ok {
+                failed += 1;
+                println!(
+                    " FAIL with {}: {}",
+                    outcome.status(),
+                    outcome.error().unwrap_or_default()
+                );
+                continue 'parts;
+            }
+            if run >= options.warmup {
+                samples.push(duration);
+            }
+        }
+        let stats = BenchStats::from_samples(&samples);
+
+        print!(
+            " min {} median {} mean {} stddev {}",
+            format_duration(&stats.min),
+            format_duration(&stats.median),
+            format_duration(&stats.mean),
+            format_duration(&stats.stddev)
+        );
+        match baseline.change_percent(day, part, &stats) {
+            Some(change) if change > options.threshold_percent => {
+                regressions += 1;
+                println!(" ({change:+.1}% vs baseline, REGRESSION)");
+            }
+            Some(change) => println!(" ({change:+.1}% vs baseline)"),
+            None => println!(),
+        }
+
+        if options.save_baseline {
+            baseline.record(day, part, &stats);
+        }
+    }
+
+    if options.save_baseline {
+        baseline.save(&baseline_file)?;
+        println!("Saved baseline to {}", baseline_file.display());
+    }
+    if regressions > 0 {
+        println!(
+            "{regressions} part(s) slower than baseline by more than {}%",
+            options.threshold_percent
+        );
+    }
+
+    if failed > 0 {
+        println!("{failed} part(s) failed");
+    }
+
+    Ok(regressions == 0 && failed == 0)
+}
+
+/// Parses `day` and `day,part` args into the (day, part) pairs they ask for.
+fn parse_day_parts(args: &[String]) -> BTreeSet<(usize, usize)> {
+    args.iter()
+        .enumerate()
+        .flat_map(|(i, arg)| {
+            let (day, part) = match arg.split_once(',') {
+                Some((day, part)) => (day, Some(part)),
+                None => (arg.as_str(), None),
+            };
+            let day = day.parse::<usize>().ok().filter(|day| (1..=25).contains(day));
+            let part = part.map(|part| part.parse::<usize>().ok().filter(|part| (1..=2).contains(part)));
+            match (day, part) {
+                (Some(day), None) => vec![(day, 1), (day, 2)],
+                (Some(day), Some(Some(part))) => vec![(day, part)],
+                _ => panic!("Invalid arg {} at at position {}.  Expected to be either a day from 1-25 or a day from 1-25 followed by a comma and a part from 1-2", arg, i),
+            }
+        })
+        .collect()
+}
+
+fn find_parts_to_run(year: &dyn Year, all: bool, days: &BTreeSet<(usize, usize)>) -> Vec<DayPartFn> {
+    let day_parts = year.day_parts();
+    if all {
+        return day_parts;
+    }
+    if days.is_empty() {
+        let latest_day = year.latest_day();
+        return day_parts.into_iter().filter(|dp| Some(dp.day) == latest_day).collect();
+    }
+    day_parts.into_iter().filter(|dp| days.contains(&(dp.day, dp.part))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, path::Path};
+
+    use super::{find_parts_to_run, parse_day_parts};
+    use crate::{registry::Year, AocError, DailyInput, DayPartFn};
+
+    struct TestYear;
+
+    fn part(_input: DailyInput) -> Result<String, AocError> {
+        Ok("".to_string())
+    }
+
+    impl Year for TestYear {
+        fn year(&self) -> usize {
+            2000
+        }
+
+        fn dir(&self) -> &'static Path {
+            Path::new(".")
+        }
+
+        fn day_parts(&self) -> Vec<DayPartFn> {
+            (1..=3).flat_map(|day| [DayPartFn::new(day, 1, part), DayPartFn::new(day, 2, part)]).collect()
+        }
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn day_parts_run(all: bool, days: &[&str]) -> Vec<(usize, usize)> {
+        find_parts_to_run(&TestYear, all, &parse_day_parts(&args(days)))
+            .iter()
+            .map(|dp| (dp.day, dp.part))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_day_parts() {
+        assert_eq!(
+            parse_day_parts(&args(&["12,1", "3"])),
+            BTreeSet::from([(3, 1), (3, 2), (12, 1)])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid arg 26")]
+    fn test_parse_day_parts_invalid() {
+        parse_day_parts(&args(&["26"]));
+    }
+
+    #[test]
+    fn test_find_parts_to_run() {
+        assert_eq!(day_parts_run(false, &[]), vec![(3, 1), (3, 2)]);
+        assert_eq!(day_parts_run(false, &["1", "2,2"]), vec![(1, 1), (1, 2), (2, 2)]);
+        assert_eq!(day_parts_run(true, &[]).len(), 6);
+    }
+}
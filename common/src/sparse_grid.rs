@@ -0,0 +1,286 @@
+use crate::{
+    coord::{rc, Direction, RowCol, NSEW},
+    grid::Grid,
+};
+use std::{collections::HashMap, fmt::Display, iter::successors, ops::RangeInclusive};
+use strum::IntoEnumIterator;
+
+/// Bounds that hold no cells at all, so an empty grid has empty ranges of rows and cols.
+const EMPTY_BOUNDS: (RowCol, RowCol) = (rc(0, 0), rc(-1, -1));
+
+/// Cells of a grid kept only where they've been set, for coordinates that are huge, negative or just not known up
+/// front.  The bounding box grows and shrinks to fit the cells that are set, and [SparseGrid::to_grid] fills in the
+/// gaps to get a dense [Grid] of the same area.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T = u8> {
+    cells: HashMap<RowCol, T>,
+    bounds: Option<(RowCol, RowCol)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, rc: RowCol) -> Option<T> {
+        self.cells.get(&rc).cloned()
+    }
+
+    pub fn set(&mut self, rc: RowCol, value: T) {
+        self.bounds = Some(match self.bounds {
+            None => (rc, rc),
+            Some((min, max)) => (
+                RowCol::new(min.row().min(rc.row()), min.col().min(rc.col())),
+                RowCol::new(max.row().max(rc.row()), max.col().max(rc.col())),
+            ),
+        });
+        self.cells.insert(rc, value);
+    }
+
+    /// Sets every cell from one end of `col_range` through the other, in `row`.  The ends can come in either order,
+    /// like the two ends of a line.
+    pub fn fill_horizontal(&mut self, row: i64, col_range: RangeInclusive<i64>, fill_with: T) {
+        let (from, through) = ordered(col_range);
+
+        for col in from..=through {
+            self.set((row, col).into(), fill_with.clone());
+        }
+    }
+
+    /// Sets every cell from one end of `row_range` through the other, in `col`.  The ends can come in either order.
+    pub fn fill_vertical(&mut self, row_range: RangeInclusive<i64>, col: i64, fill_with: T) {
+        let (from, through) = ordered(row_range);
+
+        for row in from..=through {
+            self.set((row, col).into(), fill_with.clone());
+        }
+    }
+
+    /// The set cells north, south, east and west of `rc`, in that order.
+    pub fn neighbors4(&self, rc: RowCol) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        NSEW.iter().filter_map(move |direction| self.located(rc.plus(direction)))
+    }
+
+    /// The set cells in all eight directions around `rc`, clockwise from north.
+    pub fn neighbors8(&self, rc: RowCol) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        Direction::iter().filter_map(move |direction| self.located(rc.plus(&direction)))
+    }
+
+    /// The set cells stepping away from `rc` in `direction`, not including `rc` itself, until the edge of the
+    /// bounding box.  Unset cells along the way are skipped.
+    pub fn ray(&self, rc: RowCol, direction: Direction) -> impl Iterator<Item = (RowCol, T)> + '_ {
+        successors(Some(rc.plus(&direction)), move |next| Some(next.plus(&direction)))
+            .take_while(|next| self.is_in_bounds(*next))
+            .filter_map(|next| self.located(next))
+    }
+
+    /// All set cells whose values match `predicate`, in no particular order.
+    pub fn cells_matching<'a>(&'a self, predicate: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = (RowCol, T)> + 'a {
+        self.cells.iter().filter(move |(_, value)| predicate(value)).map(|(rc, value)| (*rc, value.clone()))
+    }
+
+    /// A dense grid covering the bounding box, with `fill` wherever nothing's set.
+    pub fn to_grid(&self, fill: T) -> Grid<T> {
+        let (min, max) = self.bounds.unwrap_or(EMPTY_BOUNDS);
+        let mut grid = Grid::new_repeating(min, max, fill);
+        for (rc, value) in &self.cells {
+            grid.set(*rc, value.clone());
+        }
+        grid
+    }
+
+    fn located(&self, rc: RowCol) -> Option<(RowCol, T)> {
+        self.get(rc).map(|value| (rc, value))
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn contains(&self, rc: RowCol) -> bool {
+        self.cells.contains_key(&rc)
+    }
+
+    pub fn remove(&mut self, rc: RowCol) -> Option<T> {
+        let removed = self.cells.remove(&rc);
+        if removed.is_some() && self.is_on_edge(rc) {
+            self.bounds = self.cells.keys().fold(None, |bounds, rc| {
+                Some(match bounds {
+                    None => (*rc, *rc),
+                    Some((min, max)) => (
+                        RowCol::new(min.row().min(rc.row()), min.col().min(rc.col())),
+                        RowCol::new(max.row().max(rc.row()), max.col().max(rc.col())),
+                    ),
+                })
+            });
+        }
+        removed
+    }
+
+    /// The number of cells that are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The top left corner of the bounding box, if anything's set.
+    pub fn min(&self) -> Option<RowCol> {
+        self.bounds.map(|(min, _)| min)
+    }
+
+    /// The bottom right corner of the bounding box, if anything's set.
+    pub fn max(&self) -> Option<RowCol> {
+        self.bounds.map(|(_, max)| max)
+    }
+
+    pub fn rows(&self) -> RangeInclusive<i64> {
+        let (min, max) = self.bounds.unwrap_or(EMPTY_BOUNDS);
+        min.row()..=max.row()
+    }
+
+    pub fn cols(&self) -> RangeInclusive<i64> {
+        let (min, max) = self.bounds.unwrap_or(EMPTY_BOUNDS);
+        min.col()..=max.col()
+    }
+
+    /// The set cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&RowCol, &T)> {
+        self.cells.iter()
+    }
+
+    fn is_in_bounds(&self, rc: RowCol) -> bool {
+        self.rows().contains(&rc.row()) && self.cols().contains(&rc.col())
+    }
+
+    fn is_on_edge(&self, rc: RowCol) -> bool {
+        match self.bounds {
+            Some((min, max)) => {
+                rc.row() == min.row() || rc.row() == max.row() || rc.col() == min.col() || rc.col() == max.col()
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T: Clone> FromIterator<(RowCol, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (RowCol, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T: Clone> Extend<(RowCol, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (RowCol, T)>>(&mut self, iter: I) {
+        for (rc, value) in iter {
+            self.set(rc, value);
+        }
+    }
+}
+
+/// Shown the same as a dense [Grid], with `.` wherever nothing's set.
+impl Display for SparseGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_grid(b'.').fmt(f)
+    }
+}
+
+/// The lower and upper ends of `range`, whichever way round they were given.
+fn ordered(range: RangeInclusive<i64>) -> (i64, i64) {
+    let (start, end) = range.into_inner();
+    (start.min(end), start.max(end))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        coord::{rc, Direction},
+        sparse_grid::SparseGrid,
+    };
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.min(), None);
+        assert!(grid.rows().is_empty());
+
+        grid.set(rc(-5, 1_000_000), b'a');
+        grid.set(rc(3, -2), b'b');
+        grid.set(rc(0, 0), b'c');
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.min(), Some(rc(-5, -2)));
+        assert_eq!(grid.max(), Some(rc(3, 1_000_000)));
+        assert_eq!(grid.get(rc(3, -2)), Some(b'b'));
+        assert_eq!(grid.get(rc(3, -1)), None);
+
+        assert_eq!(grid.remove(rc(-5, 1_000_000)), Some(b'a'));
+        assert_eq!(grid.min(), Some(rc(0, -2)));
+        assert_eq!(grid.max(), Some(rc(3, 0)));
+        assert_eq!(grid.remove(rc(-5, 1_000_000)), None);
+
+        grid.remove(rc(3, -2));
+        grid.remove(rc(0, 0));
+        assert!(grid.is_empty());
+        assert_eq!(grid.max(), None);
+    }
+
+    #[test]
+    fn test_neighbors_and_ray() {
+        let grid = [(rc(0, 0), b'a'), (rc(-1, 0), b'b'), (rc(1, 1), b'c'), (rc(0, 4), b'd')]
+            .into_iter()
+            .collect::<SparseGrid>();
+
+        assert_eq!(grid.neighbors4(rc(0, 0)).collect::<Vec<_>>(), vec![(rc(-1, 0), b'b')]);
+        assert_eq!(
+            grid.neighbors8(rc(0, 0)).collect::<Vec<_>>(),
+            vec![(rc(-1, 0), b'b'), (rc(1, 1), b'c')]
+        );
+        assert_eq!(
+            grid.ray(rc(0, 0), Direction::E).collect::<Vec<_>>(),
+            vec![(rc(0, 4), b'd')]
+        );
+        assert_eq!(grid.ray(rc(0, 0), Direction::W).count(), 0);
+        assert_eq!(grid.cells_matching(|b| *b > b'b').count(), 2);
+    }
+
+    #[test]
+    fn test_fill() {
+        let mut grid = SparseGrid::new();
+        grid.fill_horizontal(0, 2..=4, b'#');
+        assert_eq!(grid.cols(), 2..=4);
+        assert_eq!(grid.get(rc(0, 4)), Some(b'#'));
+        assert_eq!(grid.get(rc(0, 5)), None);
+
+        // ends the other way round, like a line drawn back towards where it started
+        let (from, to) = (3, 1);
+        grid.fill_vertical(from..=to, 7, b'#');
+        assert_eq!(grid.rows(), 0..=3);
+        assert_eq!(grid.get(rc(1, 7)), Some(b'#'));
+        assert_eq!(grid.get(rc(3, 7)), Some(b'#'));
+        assert_eq!(grid.get(rc(4, 7)), None);
+        assert_eq!(grid.len(), 6);
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let mut grid = SparseGrid::new();
+        grid.fill_horizontal(-1, 2..=4, b'#');
+        grid.fill_vertical(0..=1, 3, b'#');
+
+        let dense = grid.to_grid(b'.');
+        assert_eq!(dense.min(), rc(-1, 2));
+        assert_eq!(dense.max(), rc(1, 4));
+        assert_eq!(grid.to_string(), "[-1, 2]-[1, 4]:\n###\n.#.\n.#.\n");
+        assert_eq!(SparseGrid::<u8>::new().to_grid(b'.').row_count(), 0);
+    }
+}